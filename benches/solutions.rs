@@ -0,0 +1,34 @@
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Benchmarks every registered solution against its real input.  Each is named `<year>/<day>`,
+//! so `cargo bench -- 2024` or `cargo bench -- 2024/07` runs a single year or day.
+
+use adventofcode_rust::aoc::{find_data_dir, Input};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use itertools::Itertools;
+
+fn solutions(c: &mut Criterion) {
+    let datadir = find_data_dir(None).unwrap();
+    let registry = adventofcode_rust::registry();
+    for (year, days) in &registry.iter().chunk_by(|(date, _)| date.year()) {
+        // Some days take seconds, so settle for criterion's minimum sample size
+        let mut group = c.benchmark_group(year.to_string());
+        group.sample_size(10);
+        for (date, solution) in days {
+            let Ok(input) = Input::load(&date.input_path(&datadir)) else {
+                continue;
+            };
+            group.bench_function(format!("{:02}", date.day()), |b| {
+                b.iter(|| solution.run(black_box(&input)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
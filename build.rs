@@ -0,0 +1,82 @@
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Generates one test per example input under `data/`, included by `tests/examples.rs`.  Examples
+//! with no `.answers` file yet get an ignored test, so they still show up in the test list.
+//! Years whose feature is disabled get no tests, since their solutions aren't built.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let datadir = manifest_dir.join("data");
+    println!("cargo:rerun-if-changed={}", datadir.display());
+
+    let mut tests = String::new();
+    for (year, day, name) in examples(&datadir) {
+        if env::var_os(format!("CARGO_FEATURE_Y{year}")).is_none() {
+            continue;
+        }
+        let answers = datadir
+            .join(year.to_string())
+            .join(format!("{name}.answers"));
+        if !answers.is_file() {
+            writeln!(tests, "#[ignore = \"no answers file\"]").unwrap();
+        }
+        writeln!(
+            tests,
+            "#[test]\nfn y{year}_{name}() {{\n    check_example({year}, {day}, \"{name}\");\n}}\n"
+        )
+        .unwrap();
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("example_tests.rs"), tests).unwrap();
+}
+
+/// Every example input as (year, day, file stem), e.g. (2024, 10, "10example2"), in order
+fn examples(datadir: &Path) -> Vec<(u32, u32, String)> {
+    let mut examples = Vec::new();
+    let Ok(years) = fs::read_dir(datadir) else {
+        return examples;
+    };
+    for year_entry in years.flatten() {
+        let Some(year) = year_entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        for entry in fs::read_dir(year_entry.path()).unwrap().flatten() {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "txt") {
+                continue;
+            }
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let (Some(day), Some(variant)) = (stem.get(..2), stem.get(2..)) else {
+                continue;
+            };
+            let Ok(day) = day.parse::<u32>() else {
+                continue;
+            };
+            if variant.is_empty() || !variant.chars().all(|c| c.is_ascii_alphanumeric()) {
+                continue;
+            }
+            if variant.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+            examples.push((year, day, stem.to_string()));
+        }
+    }
+    examples.sort();
+    examples
+}
@@ -0,0 +1,153 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! The known answers for each input, and whether a run's answers match them
+
+use super::{Answer, SolutionResult};
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Known correct answers for one input, kept beside it as e.g. `data/2024/07.answers` or
+/// `data/2024/07example.answers`:
+///
+/// ```text
+/// part1: 3749
+/// part2: 11387
+/// ```
+///
+/// Either part may be left out if its answer isn't known yet.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// The outcome of checking one part's answer against the answers file
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There's an answer but no record of the right one
+    Unknown,
+    /// The solution didn't produce an answer, and there's no record of one either
+    Unsolved,
+    /// There is no second part
+    NotApplicable,
+}
+
+impl Verdict {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+            Verdict::Unsolved => "UNSOLVED",
+            Verdict::NotApplicable => "N/A",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+            Verdict::Unsolved => write!(f, "UNSOLVED"),
+            Verdict::NotApplicable => write!(f, "N/A"),
+        }
+    }
+}
+
+impl Answers {
+    /// Where the answers for the input at `input` are kept
+    pub fn path(input: &Path) -> PathBuf {
+        input.with_extension("answers")
+    }
+
+    /// The answers for the input at `input`, or none if there's no answers file
+    pub fn load(input: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(Answers::path(input)) {
+            Ok(text) => Ok(Answers::parse(&text)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(text: &str) -> Answers {
+        let mut answers = Answers::default();
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = Some(value.trim().to_string());
+            match key.trim() {
+                "part1" => answers.part1 = value,
+                "part2" => answers.part2 = value,
+                _ => {}
+            }
+        }
+        answers
+    }
+
+    /// Check both parts' answers.  A solution that used to give an answer but no longer does
+    /// fails.
+    pub fn verify(&self, result: &SolutionResult) -> [Verdict; 2] {
+        let check = |expected: &Option<String>, actual: &Answer| match (expected, actual) {
+            (Some(expected), Answer::Value(actual)) if actual.matches(expected) => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            (None, Answer::Value(_)) => Verdict::Unknown,
+            (None, Answer::NotImplemented | Answer::GaveUp) => Verdict::Unsolved,
+            (None, Answer::NoPart2) => Verdict::NotApplicable,
+        };
+        [check(&self.part1, &result.a), check(&self.part2, &result.b)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_verify_each_part() {
+        let answers = Answers::parse("part1: 3749\n");
+        let result = SolutionResult::new(3749, 11387);
+        assert_eq!(answers.verify(&result), [Verdict::Pass, Verdict::Unknown]);
+
+        let answers = Answers::parse("part1: 3749\npart2: 11386\n");
+        assert_eq!(
+            answers.verify(&result)[1],
+            Verdict::Fail {
+                expected: "11386".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn answers_without_values_are_not_compared() {
+        let result = SolutionResult {
+            a: Answer::GaveUp,
+            b: Answer::NoPart2,
+        };
+        assert_eq!(
+            Answers::default().verify(&result),
+            [Verdict::Unsolved, Verdict::NotApplicable]
+        );
+        assert_eq!(
+            Answers::parse("part1: 524\n").verify(&result)[0],
+            Verdict::Fail {
+                expected: "524".to_string()
+            }
+        );
+    }
+}
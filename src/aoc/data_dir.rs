@@ -0,0 +1,110 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Finding the directory of puzzle inputs and answers
+
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the data directory when `--datadir` isn't given
+pub const DATA_DIR_ENV_VAR: &str = "AOC_DATA_DIR";
+
+#[derive(Debug)]
+pub enum DataDirError {
+    /// The current directory couldn't be determined, so there was nowhere to search from
+    CurrentDir(io::Error),
+    /// None of the searched locations was a data directory
+    NotFound { searched: Vec<PathBuf> },
+}
+
+impl Display for DataDirError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataDirError::CurrentDir(err) => {
+                write!(f, "can't search for a data directory: {err}")
+            }
+            DataDirError::NotFound { searched } => {
+                write!(f, "no data directory found; searched:")?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataDirError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataDirError::CurrentDir(err) => Some(err),
+            DataDirError::NotFound { .. } => None,
+        }
+    }
+}
+
+/// A data directory holds one subdirectory per year, e.g. `data/2024/07.txt`
+fn is_data_dir(path: &Path) -> bool {
+    let Ok(entries) = fs::read_dir(path) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        entry.path().is_dir() && name.len() == 4 && name.bytes().all(|c| c.is_ascii_digit())
+    })
+}
+
+/// Locate the input data directory.  An explicitly requested directory (the `--datadir`
+/// argument) takes precedence, then the `AOC_DATA_DIR` environment variable, and otherwise
+/// the nearest `data` directory in or above the current directory.
+pub fn find_data_dir(datadir: Option<&Path>) -> Result<PathBuf, DataDirError> {
+    let env_datadir = std::env::var_os(DATA_DIR_ENV_VAR).map(PathBuf::from);
+    match datadir.map(Path::to_path_buf).or(env_datadir) {
+        Some(explicit) => search_data_dirs([explicit]),
+        None => {
+            let current_dir = std::env::current_dir().map_err(DataDirError::CurrentDir)?;
+            search_data_dirs(current_dir.ancestors().map(|dir| dir.join("data")))
+        }
+    }
+}
+
+fn search_data_dirs(
+    candidates: impl IntoIterator<Item = PathBuf>,
+) -> Result<PathBuf, DataDirError> {
+    let mut searched = vec![];
+    for candidate in candidates {
+        if is_data_dir(&candidate) {
+            return Ok(candidate);
+        }
+        searched.push(candidate);
+    }
+    Err(DataDirError::NotFound { searched })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_dir_search_reports_searched_paths() {
+        let missing = vec![PathBuf::from("no/such/data"), PathBuf::from("no/data")];
+        match search_data_dirs(missing.clone()) {
+            Err(DataDirError::NotFound { searched }) => assert_eq!(searched, missing),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn data_dir_search_finds_repo_data() {
+        let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let candidates = [PathBuf::from("no/such/data"), data.clone()];
+        assert_eq!(search_data_dirs(candidates).unwrap(), data);
+    }
+}
@@ -0,0 +1,606 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Two-dimensional vectors and rectangles, and the `Grid` most puzzles are laid out on
+
+use super::SolveError;
+use itertools::{Itertools, Product};
+use std::fmt::Display;
+use std::ops;
+use std::ops::{Add, Index, IndexMut, Range};
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Default)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+    pub fn from_tuple(t: (T, T)) -> Vec2<T> {
+        Vec2::new(t.0, t.1)
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: ops::Add<Output = T>> ops::Add<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: ops::Add<Output = T>> ops::Add<(T, T)> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: (T, T)) -> Self::Output {
+        Vec2::new(self.x + rhs.0, self.y + rhs.1)
+    }
+}
+
+impl<T: ops::AddAssign> ops::AddAssign<Vec2<T>> for Vec2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: ops::AddAssign> ops::AddAssign<(T, T)> for Vec2<T> {
+    fn add_assign(&mut self, rhs: (T, T)) {
+        self.x += rhs.0;
+        self.y += rhs.1;
+    }
+}
+
+impl<T: ops::Sub<Output = T>> ops::Sub<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: ops::Sub<Output = T>> ops::Sub<(T, T)> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: (T, T)) -> Self::Output {
+        Vec2::new(self.x - rhs.0, self.y - rhs.1)
+    }
+}
+
+impl<T: ops::SubAssign> ops::SubAssign<Vec2<T>> for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: ops::SubAssign> ops::SubAssign<(T, T)> for Vec2<T> {
+    fn sub_assign(&mut self, rhs: (T, T)) {
+        self.x -= rhs.0;
+        self.y -= rhs.1;
+    }
+}
+
+impl<T: Clone + ops::Mul<Output = T>> ops::Mul<&T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: &T) -> Self::Output {
+        Vec2::new(self.x * rhs.clone(), self.y * rhs.clone())
+    }
+}
+
+impl<T: Clone + ops::MulAssign> ops::AddAssign<&T> for Vec2<T> {
+    fn add_assign(&mut self, rhs: &T) {
+        self.x *= rhs.clone();
+        self.y *= rhs.clone();
+    }
+}
+
+/// Unit steps to the four orthogonally adjacent cells, with y growing downwards: down, right,
+/// up, left
+pub const CARDINALS: [Vec2<isize>; 4] = [
+    Vec2 { x: 0, y: 1 },
+    Vec2 { x: 1, y: 0 },
+    Vec2 { x: 0, y: -1 },
+    Vec2 { x: -1, y: 0 },
+];
+
+/// Unit steps to all eight surrounding cells: the cardinals, then the diagonals
+pub const ALL_DIRECTIONS: [Vec2<isize>; 8] = [
+    Vec2 { x: 0, y: 1 },
+    Vec2 { x: 1, y: 0 },
+    Vec2 { x: 0, y: -1 },
+    Vec2 { x: -1, y: 0 },
+    Vec2 { x: 1, y: -1 },
+    Vec2 { x: 1, y: 1 },
+    Vec2 { x: -1, y: 1 },
+    Vec2 { x: -1, y: -1 },
+];
+
+#[derive(Debug)]
+pub struct Rect<T> {
+    pub base: Vec2<T>,
+    pub dimensions: Vec2<T>,
+}
+
+impl<T: std::cmp::PartialOrd> Rect<T> {
+    pub fn contains(&self, point: Vec2<T>) -> bool {
+        self.base.x <= point.x
+            && point.x < self.dimensions.x
+            && self.base.y <= point.y
+            && point.y < self.dimensions.y
+    }
+}
+
+impl<T: Copy + std::iter::Step + Add<Output = T>> Rect<T> {
+    pub fn all_points(&self) -> Product<Range<T>, Range<T>> {
+        (self.base.x..(self.base.x + self.dimensions.x))
+            .cartesian_product(self.base.y..(self.base.y + self.dimensions.y))
+    }
+}
+
+#[derive(Clone)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: isize,
+    height: isize,
+}
+
+/// A grid's lines weren't all the same width
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GridError {
+    /// Counting from 1
+    pub line: usize,
+    pub width: usize,
+    /// The width of the first line
+    pub expected: usize,
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} is {} wide, but the grid is {} wide",
+            self.line, self.width, self.expected
+        )
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl From<GridError> for SolveError {
+    fn from(err: GridError) -> SolveError {
+        SolveError::Parse {
+            line: err.line,
+            column: err.width.min(err.expected) + 1,
+            message: err.to_string(),
+        }
+    }
+}
+
+/// The lines of a grid's text, without line endings.  A final line ending is optional, and
+/// blank lines at the end are ignored.
+fn grid_lines(input: &[u8]) -> Vec<&[u8]> {
+    let mut lines: Vec<&[u8]> = input
+        .split(|&c| c == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+impl Grid<u8> {
+    /// A grid of the bytes of `input`, one row per line.  Panics if the lines aren't all the
+    /// same width, so it's meant for tests and grids written into the code; parse puzzle
+    /// input with `parse` or `parse_padded`.
+    pub fn from_u8(input: &[u8]) -> Grid<u8> {
+        Grid::parse(input).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// A grid of the bytes of `input`, one row per line, which must all be the same width.
+    /// Lines may end with `\n` or `\r\n`, and the last needn't end at all.
+    pub fn parse(input: &[u8]) -> Result<Grid<u8>, GridError> {
+        Grid::parse_with(input, |c| c)
+    }
+
+    /// Like `parse`, but lines shorter than the longest are padded out with `fill`
+    pub fn parse_padded(input: &[u8], fill: u8) -> Grid<u8> {
+        let lines = grid_lines(input);
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let mut grid = Grid {
+            data: Vec::with_capacity(width * lines.len()),
+            width: width as isize,
+            height: lines.len() as isize,
+        };
+        for line in lines {
+            grid.data.extend_from_slice(line);
+            grid.data.resize(grid.data.len() + width - line.len(), fill);
+        }
+        grid
+    }
+}
+
+impl<T: Default> Grid<T> {
+    pub fn new(width: isize, height: isize) -> Self {
+        let mut grid = Grid {
+            data: Vec::new(),
+            width,
+            height,
+        };
+        grid.data
+            .resize_with((grid.width * grid.height) as usize, Default::default);
+        grid
+    }
+
+    pub fn width(&self) -> isize {
+        self.width
+    }
+    pub fn height(&self) -> isize {
+        self.height
+    }
+
+    pub fn area(&self) -> Rect<isize> {
+        Rect {
+            base: Vec2::new(0, 0),
+            dimensions: Vec2::new(self.width, self.height),
+        }
+    }
+
+    pub fn data_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    pub fn data_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
+
+    // pub fn rows_mut(&mut self) -> impl Iterator + '_ {
+    //     self.data.as_mut_slice().chunks(self.width)
+    // }
+    //
+    // // pub fn row(&mut self, row: usize) {
+    // //     self.rows().nth(row).unwrap()
+    // // }
+    //
+    // pub fn cols_mut(&mut self) -> impl Iterator + '_ {
+    //     (0..self.width).map(|i| self.data.iter_mut().skip(i).step_by(self.width))
+    // }
+
+    pub fn col(&self, index: isize) -> impl Iterator<Item = &T> {
+        self.data_slice()
+            .iter()
+            .skip(index as usize)
+            .step_by(self.width as usize)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    pub fn row(&self, index: isize) -> impl Iterator<Item = &T> {
+        self.data_slice()
+            .iter()
+            .skip((index * self.width) as usize)
+            .take(self.width as usize)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.height).map(|x| self.row(x))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Like `Grid::parse`, but each byte is turned into a cell by `cell`
+    pub fn parse_with(input: &[u8], cell: impl FnMut(u8) -> T) -> Result<Grid<T>, GridError> {
+        let lines = grid_lines(input);
+        let width = lines.first().map_or(0, |line| line.len());
+        if let Some((index, line)) = lines
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.len() != width)
+        {
+            return Err(GridError {
+                line: index + 1,
+                width: line.len(),
+                expected: width,
+            });
+        }
+        Ok(Grid {
+            data: lines.concat().into_iter().map(cell).collect(),
+            width: width as isize,
+            height: lines.len() as isize,
+        })
+    }
+
+    /// A grid of the same size, with each cell turned into a new one by `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The grid as text, one line per row, with `cell` choosing each cell's character
+    pub fn render(&self, cell: impl FnMut(&T) -> char) -> String {
+        self.render_overlay(cell, [])
+    }
+
+    /// Like `render`, but with markers drawn over some cells, e.g. to show a path through
+    /// the grid.  Markers outside the grid are ignored.
+    pub fn render_overlay(
+        &self,
+        cell: impl FnMut(&T) -> char,
+        markers: impl IntoIterator<Item = (Vec2<isize>, char)>,
+    ) -> String {
+        let mut chars: Vec<char> = self.data.iter().map(cell).collect();
+        for (pos, marker) in markers {
+            if let Some(offset) = self.offset(pos) {
+                chars[offset] = marker;
+            }
+        }
+        chars
+            .chunks(self.width.max(1) as usize)
+            .map(|row| row.iter().chain(['\n'].iter()).collect::<String>())
+            .collect()
+    }
+
+    /// Whether `pos` is inside the grid
+    pub fn contains(&self, pos: Vec2<isize>) -> bool {
+        (0..self.width).contains(&pos.x) && (0..self.height).contains(&pos.y)
+    }
+
+    pub fn get(&self, pos: Vec2<isize>) -> Option<&T> {
+        self.offset(pos).map(|offset| &self.data[offset])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2<isize>) -> Option<&mut T> {
+        self.offset(pos).map(|offset| &mut self.data[offset])
+    }
+
+    /// The positions of the cells up, down, left and right of `pos` that are inside the grid
+    pub fn neighbours(&self, pos: Vec2<isize>) -> impl Iterator<Item = Vec2<isize>> + '_ {
+        self.steps(pos, &CARDINALS)
+    }
+
+    /// The positions of the cells surrounding `pos`, diagonals included, that are inside the
+    /// grid
+    pub fn neighbours8(&self, pos: Vec2<isize>) -> impl Iterator<Item = Vec2<isize>> + '_ {
+        self.steps(pos, &ALL_DIRECTIONS)
+    }
+
+    /// The cells up, down, left and right of `pos`, where they're inside the grid
+    pub fn neighbour_values(&self, pos: Vec2<isize>) -> impl Iterator<Item = &T> {
+        self.neighbours(pos).map(|pos| &self[pos])
+    }
+
+    /// The cells surrounding `pos`, diagonals included, where they're inside the grid
+    pub fn neighbour_values8(&self, pos: Vec2<isize>) -> impl Iterator<Item = &T> {
+        self.neighbours8(pos).map(|pos| &self[pos])
+    }
+
+    /// The positions of every cell satisfying `predicate`, in reading order
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Vec2<isize>> + 'a {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(offset, _)| self.position(offset))
+    }
+
+    /// The position of the first cell, in reading order, equal to `value`
+    pub fn find(&self, value: T) -> Option<Vec2<isize>>
+    where
+        T: PartialEq,
+    {
+        self.positions(|cell| *cell == value).next()
+    }
+
+    /// The positions of every cell equal to `value`, in reading order
+    pub fn find_all(&self, value: T) -> impl Iterator<Item = Vec2<isize>> + '_
+    where
+        T: PartialEq,
+    {
+        self.positions(move |cell| *cell == value)
+    }
+
+    /// How many cells are equal to `value`
+    pub fn count(&self, value: T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.data.iter().filter(|&cell| *cell == value).count()
+    }
+
+    fn steps<'a>(
+        &'a self,
+        pos: Vec2<isize>,
+        steps: &'a [Vec2<isize>],
+    ) -> impl Iterator<Item = Vec2<isize>> + 'a {
+        steps
+            .iter()
+            .map(move |&step| pos + step)
+            .filter(|&pos| self.contains(pos))
+    }
+
+    fn position(&self, offset: usize) -> Vec2<isize> {
+        let offset = offset as isize;
+        Vec2::new(offset % self.width, offset / self.width)
+    }
+
+    fn offset(&self, pos: Vec2<isize>) -> Option<usize> {
+        self.contains(pos)
+            .then(|| (pos.y * self.width + pos.x) as usize)
+    }
+
+    /// The offset of `pos` in `data`, panicking if it's outside the grid rather than
+    /// quietly wrapping onto another row
+    fn checked_offset(&self, pos: Vec2<isize>) -> usize {
+        self.offset(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(|&c| c as char))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(|&c| c))
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field(
+                "rows",
+                &self.data.chunks(self.width.max(1) as usize).collect_vec(),
+            )
+            .finish()
+    }
+}
+
+impl<T> Index<(isize, isize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: (isize, isize)) -> &Self::Output {
+        &self[Vec2::from_tuple(index)]
+    }
+}
+
+impl<T> IndexMut<(isize, isize)> for Grid<T> {
+    fn index_mut(&mut self, index: (isize, isize)) -> &mut Self::Output {
+        &mut self[Vec2::from_tuple(index)]
+    }
+}
+
+impl<T> Index<Vec2<isize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Vec2<isize>) -> &Self::Output {
+        &self.data[self.checked_offset(index)]
+    }
+}
+
+impl<T> IndexMut<Vec2<isize>> for Grid<T> {
+    fn index_mut(&mut self, index: Vec2<isize>) -> &mut Self::Output {
+        let offset = self.checked_offset(index);
+        &mut self.data[offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_neighbours_stay_inside() {
+        let grid = Grid::from_u8(b"abc\ndef\n");
+        assert_eq!(grid.get(Vec2::new(2, 1)), Some(&b'f'));
+        assert_eq!(grid.get(Vec2::new(3, 0)), None);
+        assert_eq!(grid.get(Vec2::new(-1, 1)), None);
+        assert_eq!(
+            grid.neighbours(Vec2::new(0, 0)).collect_vec(),
+            [Vec2::new(0, 1), Vec2::new(1, 0)]
+        );
+        assert_eq!(
+            grid.neighbour_values8(Vec2::new(1, 0))
+                .copied()
+                .sorted()
+                .collect_vec(),
+            b"acdef"
+        );
+    }
+
+    #[test]
+    fn grid_parsing() {
+        let grid = Grid::parse(b"ab\r\ncd").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.data_slice(), b"abcd");
+        let grid = Grid::parse(b"abc").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 1));
+        assert_eq!(Grid::parse(b"ab\ncd\n\n").unwrap().height(), 2);
+        assert_eq!(
+            Grid::parse(b"ab\nc\nde\n").err(),
+            Some(GridError {
+                line: 2,
+                width: 1,
+                expected: 2
+            })
+        );
+        let grid = Grid::parse_padded(b"a\n bc\n", b'.');
+        assert_eq!(grid.data_slice(), b"a.. bc");
+
+        let grid = Grid::parse_with(b"12\n34\n", |c| c - b'0').unwrap();
+        assert_eq!(grid.data_slice(), [1, 2, 3, 4]);
+        let grid = grid.map(|&n| n % 2 == 0);
+        assert_eq!(grid.data_slice(), [false, true, false, true]);
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+    }
+
+    #[test]
+    fn grid_rendering() {
+        let grid = Grid::from_u8(b"#.\n.#\n");
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+        assert_eq!(
+            grid.map(|&c| c == b'#').render(|&wall| match wall {
+                true => '█',
+                false => ' ',
+            }),
+            "█ \n █\n"
+        );
+        let path = [Vec2::new(1, 0), Vec2::new(2, 0)];
+        assert_eq!(
+            grid.render_overlay(|&c| c as char, path.map(|pos| (pos, 'O'))),
+            "#O\n.#\n"
+        );
+        assert_eq!(grid.map(|&c| c as char).to_string(), "#.\n.#\n");
+    }
+
+    #[test]
+    fn grid_search() {
+        let grid = Grid::from_u8(b"#.#\n.#.\n");
+        assert_eq!(grid.find(b'.'), Some(Vec2::new(1, 0)));
+        assert_eq!(grid.find(b'x'), None);
+        assert_eq!(
+            grid.find_all(b'#').collect_vec(),
+            [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(1, 1)]
+        );
+        assert_eq!(
+            grid.positions(|&cell| cell != b'#').collect_vec(),
+            [Vec2::new(1, 0), Vec2::new(0, 1), Vec2::new(2, 1)]
+        );
+        assert_eq!(grid.count(b'#'), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside the 3x2 grid")]
+    fn grid_index_is_bounds_checked() {
+        let grid = Grid::from_u8(b"abc\ndef\n");
+        let _ = grid[Vec2::new(3, 0)];
+    }
+}
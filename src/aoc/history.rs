@@ -0,0 +1,173 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Timings of past runs, kept so later runs can be compared with them
+
+use super::report::nanos;
+use super::{Date, Report};
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+/// One solution's timing on one input, as recorded in the history
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryEntry {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// The git commit that was checked out, or empty if unknown
+    pub commit: String,
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+}
+
+impl HistoryEntry {
+    const CSV_HEADER: &str = "timestamp,commit,year,day,input,runs,min_ns,median_ns";
+
+    /// History entries for a run's reports, stamped with the current time and commit
+    pub fn from_reports(reports: &[Report]) -> Vec<HistoryEntry> {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since_epoch| since_epoch.as_secs());
+        let commit = git_commit().unwrap_or_default();
+        reports
+            .iter()
+            .map(|report| HistoryEntry {
+                timestamp,
+                commit: commit.clone(),
+                year: report.date.year(),
+                day: report.date.day(),
+                input: report.input.clone(),
+                runs: report.timings.runs().len(),
+                min_ns: nanos(report.timings.min()),
+                median_ns: nanos(report.timings.median()),
+            })
+            .collect()
+    }
+
+    fn parse(line: &str) -> Option<HistoryEntry> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [timestamp, commit, year, day, input, runs, min_ns, median_ns] = fields[..] else {
+            return None;
+        };
+        Some(HistoryEntry {
+            timestamp: timestamp.parse().ok()?,
+            commit: commit.to_string(),
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            input: input.to_string(),
+            runs: runs.parse().ok()?,
+            min_ns: min_ns.parse().ok()?,
+            median_ns: median_ns.parse().ok()?,
+        })
+    }
+
+    fn csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit,
+            self.year,
+            self.day,
+            self.input,
+            self.runs,
+            self.min_ns,
+            self.median_ns
+        )
+    }
+}
+
+/// Timings of past runs, kept in `history.csv` in the data directory
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    pub fn path(datadir: &Path) -> PathBuf {
+        datadir.join("history.csv")
+    }
+
+    /// The history at `path`, which is empty if nothing has been recorded yet.  Lines that
+    /// can't be parsed are skipped.
+    pub fn load(path: &Path) -> io::Result<History> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(History {
+            entries: text.lines().filter_map(HistoryEntry::parse).collect(),
+        })
+    }
+
+    pub fn append(path: &Path, entries: &[HistoryEntry]) -> io::Result<()> {
+        let new = !path.exists();
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        if new {
+            writeln!(file, "{}", HistoryEntry::CSV_HEADER)?;
+        }
+        for entry in entries {
+            writeln!(file, "{}", entry.csv_row())?;
+        }
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// The most recently recorded timing of this date's solution on the named input
+    pub fn latest(&self, date: Date, input: &str) -> Option<&HistoryEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                entry.year == date.year() && entry.day == date.day() && entry.input == input
+            })
+            .max_by_key(|entry| entry.timestamp)
+    }
+}
+
+/// The short hash of the git commit checked out in the current directory, if any
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !commit.is_empty()).then_some(commit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_entries_round_trip() {
+        let entry = HistoryEntry {
+            timestamp: 1733500000,
+            commit: "5a4ffe3".to_string(),
+            year: 2024,
+            day: 7,
+            input: "07".to_string(),
+            runs: 10,
+            min_ns: 1200,
+            median_ns: 1500,
+        };
+        assert_eq!(HistoryEntry::parse(&entry.csv_row()), Some(entry));
+        assert_eq!(HistoryEntry::parse(HistoryEntry::CSV_HEADER), None);
+    }
+}
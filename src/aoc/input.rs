@@ -0,0 +1,232 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Loading a puzzle input, and the parameters that can come with it
+
+use super::SolveError;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::string::FromUtf8Error;
+
+#[derive(Debug)]
+pub enum InputError {
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Utf8 {
+        path: PathBuf,
+        source: FromUtf8Error,
+    },
+    /// The input's `.params` file is malformed
+    Params {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Read { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+            InputError::Utf8 { path, source } => {
+                write!(f, "{} is not valid UTF-8: {source}", path.display())
+            }
+            InputError::Params { path, message } => write!(f, "{}: {message}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Read { source, .. } => Some(source),
+            InputError::Utf8 { source, .. } => Some(source),
+            InputError::Params { .. } => None,
+        }
+    }
+}
+
+/// Puzzle input as the solutions see it: valid UTF-8 with no byte order mark and `\n` line
+/// endings only, whatever the file on disk had.  Carries any parameters given for it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Input {
+    text: String,
+    params: Params,
+}
+
+impl Input {
+    /// Read the input at `path`, along with its `.params` file if it has one
+    pub fn load(path: &Path) -> Result<Input, InputError> {
+        let bytes = fs::read(path).map_err(|source| InputError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut input = Input::from_bytes(bytes).map_err(|source| InputError::Utf8 {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let params_path = Params::path(path);
+        match fs::read_to_string(&params_path) {
+            Ok(text) => {
+                input.params = Params::parse(&text).map_err(|message| InputError::Params {
+                    path: params_path,
+                    message,
+                })?;
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(source) => {
+                return Err(InputError::Read {
+                    path: params_path,
+                    source,
+                })
+            }
+        }
+        Ok(input)
+    }
+
+    pub fn from_bytes(mut bytes: Vec<u8>) -> Result<Input, FromUtf8Error> {
+        const BOM: &[u8] = "\u{feff}".as_bytes();
+        if bytes.starts_with(BOM) {
+            bytes.drain(..BOM.len());
+        }
+        let mut text = String::from_utf8(bytes)?;
+        if text.contains('\r') {
+            text = text.replace("\r\n", "\n").replace('\r', "\n");
+        }
+        Ok(Input {
+            text,
+            params: Params::default(),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    pub fn params_mut(&mut self) -> &mut Params {
+        &mut self.params
+    }
+}
+
+/// Named values a solution can be tuned with, such as a grid size that's smaller in the
+/// examples than in the real puzzle.  Each solution has its own defaults; these override them.
+/// They come from an input's `.params` file, e.g. `data/2024/14example.params`:
+///
+/// ```text
+/// width = 11
+/// height = 7
+/// ```
+///
+/// and from `--param`, which overrides the file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Where the parameters for the input at `input` are kept
+    pub fn path(input: &Path) -> PathBuf {
+        input.with_extension("params")
+    }
+
+    /// Parse `name = value` lines, ignoring blank lines and `#` comments
+    pub fn parse(text: &str) -> Result<Params, String> {
+        let mut params = Params::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (name, value) =
+                parse_param(line).map_err(|err| format!("line {}: {err}", i + 1))?;
+            params.set(&name, &value);
+        }
+        Ok(params)
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// Add `other`'s parameters, replacing any of these with the same name
+    pub fn extend(&mut self, other: &Params) {
+        self.values.extend(
+            other
+                .values
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+    }
+
+    /// The parameter `name`, or `default` if it wasn't given
+    pub fn get<T>(&self, name: &str, default: T) -> Result<T, SolveError>
+    where
+        T: std::str::FromStr,
+        T::Err: Display,
+    {
+        match self.values.get(name) {
+            Some(value) => value.parse().map_err(|err| SolveError::Param {
+                name: name.to_string(),
+                message: format!("{err}: {value:?}"),
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Split `name=value`, as given to `--param` or in a `.params` file
+pub fn parse_param(param: &str) -> Result<(String, String), String> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, found {param:?}"))?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() {
+        return Err(format!("missing parameter name in {param:?}"));
+    }
+    Ok((name.to_string(), value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_normalises_line_endings_and_bom() {
+        let input = Input::from_bytes(b"\xef\xbb\xbfab\r\ncd\re\n".to_vec()).unwrap();
+        assert_eq!(input.as_str(), "ab\ncd\ne\n");
+    }
+
+    #[test]
+    fn params_override_defaults() {
+        let mut params = Params::parse("# example grid\nwidth = 11\n\nheight=7\n").unwrap();
+        params.extend(&Params::parse("height = 8").unwrap());
+        assert_eq!(params.get("width", 101), Ok(11));
+        assert_eq!(params.get("height", 103), Ok(8));
+        assert_eq!(params.get("seconds", 100), Ok(100));
+        assert!(params.get::<u32>("width", 0).is_ok());
+        assert!(matches!(
+            params.get::<bool>("width", false),
+            Err(SolveError::Param { .. })
+        ));
+        assert!(Params::parse("width 11").is_err());
+    }
+}
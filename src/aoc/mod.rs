@@ -0,0 +1,579 @@
+//
+// Copyright (c) 2022 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Everything the solutions share: their answers, registration and errors, and the runner,
+//! inputs and grids they use
+
+use itertools::Itertools;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+mod answers;
+mod data_dir;
+mod grid;
+mod history;
+mod input;
+mod report;
+mod runner;
+mod scaffold;
+
+pub use answers::{Answers, Verdict};
+pub use data_dir::{find_data_dir, DataDirError, DATA_DIR_ENV_VAR};
+pub use grid::{Grid, GridError, Rect, Vec2, ALL_DIRECTIONS, CARDINALS};
+pub use history::{History, HistoryEntry};
+pub use input::{parse_param, Input, InputError, Params};
+pub use report::{Format, PhaseTimings, Report, ReportRecord, Timings};
+pub use runner::{Args, Example, RunnerOptions};
+pub use scaffold::{scaffold, ScaffoldError};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolutionResult {
+    pub a: Answer,
+    pub b: Answer,
+}
+
+/// One part's answer, or why there isn't one
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Value(Value),
+    /// The part hasn't been solved in code, e.g. it was solved by hand
+    NotImplemented,
+    /// The solution couldn't find the answer for this input
+    GaveUp,
+    /// There is no second part, as on the last day of each year
+    NoPart2,
+}
+
+impl Answer {
+    pub fn value(&self) -> Option<&Value> {
+        match self {
+            Answer::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Which kind of answer this is, for machine-readable output
+    pub fn state(&self) -> &'static str {
+        match self {
+            Answer::Value(_) => "value",
+            Answer::NotImplemented => "not_implemented",
+            Answer::GaveUp => "gave_up",
+            Answer::NoPart2 => "no_part2",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Value(value) => write!(f, "{value}"),
+            Answer::NotImplemented => write!(f, "(not implemented)"),
+            Answer::GaveUp => write!(f, "(gave up)"),
+            Answer::NoPart2 => write!(f, "(no part 2)"),
+        }
+    }
+}
+
+impl<T: Into<Value>> From<T> for Answer {
+    fn from(value: T) -> Answer {
+        Answer::Value(value.into())
+    }
+}
+
+/// An answer's value, keeping the type the solution gave it.  Each displays the way the
+/// puzzle expects it to be entered, e.g. a list as `1,2,3`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Value {
+    Int(i64),
+    Text(String),
+    List(Vec<Value>),
+    Point { x: i64, y: i64 },
+}
+
+impl Value {
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Whether this is the value written as `expected`, comparing numbers as numbers
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        match self {
+            Value::Int(n) => expected.parse::<i64>() == Ok(*n),
+            Value::Text(text) => text == expected,
+            Value::List(items) => {
+                let expected = expected.split(',').collect_vec();
+                items.len() == expected.len()
+                    && items.iter().zip(expected).all(|(item, e)| item.matches(e))
+            }
+            Value::Point { x, y } => expected.split_once(',').is_some_and(|(ex, ey)| {
+                ex.trim().parse::<i64>() == Ok(*x) && ey.trim().parse::<i64>() == Ok(*y)
+            }),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{n}"),
+            Value::Text(text) => write!(f, "{text}"),
+            Value::List(items) => write!(f, "{}", items.iter().join(",")),
+            Value::Point { x, y } => write!(f, "{x},{y}"),
+        }
+    }
+}
+
+macro_rules! value_from_int {
+    ($($type:ty),*) => {
+        $(impl From<$type> for Value {
+            fn from(n: $type) -> Value {
+                match i64::try_from(n) {
+                    Ok(n) => Value::Int(n),
+                    Err(_) => Value::Text(n.to_string()),
+                }
+            }
+        }
+
+        impl From<($type, $type)> for Value {
+            fn from((x, y): ($type, $type)) -> Value {
+                match (i64::try_from(x), i64::try_from(y)) {
+                    (Ok(x), Ok(y)) => Value::Point { x, y },
+                    _ => Value::Text(format!("{x},{y}")),
+                }
+            }
+        })*
+    };
+}
+
+value_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Value {
+    fn from(text: String) -> Value {
+        Value::Text(text)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Value {
+        Value::Text(text.to_string())
+    }
+}
+
+impl<T: Copy + Into<Value>> From<&T> for Value {
+    fn from(value: &T) -> Value {
+        (*value).into()
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(items: Vec<T>) -> Value {
+        Value::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Value>> From<Vec2<T>> for Value
+where
+    (T, T): Into<Value>,
+{
+    fn from(point: Vec2<T>) -> Value {
+        (point.x, point.y).into()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Date {
+    year: u32,
+    day: u32,
+}
+
+impl Date {
+    pub fn new(year: u32, day: u32) -> Date {
+        Date { year, day }
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// The puzzle input for this date within a data directory, e.g. `data/2024/07.txt`
+    pub fn input_path(&self, datadir: &Path) -> PathBuf {
+        datadir
+            .join(self.year.to_string())
+            .join(format!("{:02}.txt", self.day))
+    }
+
+    /// Every example input for this date within a data directory, i.e. each
+    /// `data/2024/07<variant>.txt` such as `07example.txt`, `07example2.txt` or `07test.txt`,
+    /// in name order
+    pub fn example_paths(&self, datadir: &Path) -> Vec<PathBuf> {
+        let prefix = format!("{:02}", self.day);
+        let Ok(entries) = fs::read_dir(datadir.join(self.year.to_string())) else {
+            return Vec::new();
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "txt")
+                    && path
+                        .file_stem()
+                        .and_then(|stem| stem.to_str())
+                        .and_then(|stem| stem.strip_prefix(&prefix))
+                        .is_some_and(|variant| {
+                            !variant.is_empty()
+                                && !variant.starts_with(|c: char| c.is_ascii_digit())
+                        })
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    /// Every input for this date that `--watch` runs: its examples, then the real input if
+    /// there is one.  These are the `data/<year>/<dd>*.txt` files.
+    pub fn watched_paths(&self, datadir: &Path) -> Vec<PathBuf> {
+        let mut paths = self.example_paths(datadir);
+        let input_path = self.input_path(datadir);
+        if input_path.is_file() {
+            paths.push(input_path);
+        }
+        paths
+    }
+
+    /// Example number `n` for this date, e.g. `data/2024/07example2.txt`.  A day with only one
+    /// example usually calls it `07example.txt`, which is example 1.
+    pub fn example_path(&self, datadir: &Path, n: u32) -> Option<PathBuf> {
+        let yeardir = datadir.join(self.year.to_string());
+        let numbered = yeardir.join(format!("{:02}example{n}.txt", self.day));
+        let unnumbered = yeardir.join(format!("{:02}example.txt", self.day));
+        if numbered.is_file() {
+            Some(numbered)
+        } else if n == 1 && unnumbered.is_file() {
+            Some(unnumbered)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Day {:02}", self.year, self.day)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DateFilter {
+    year: u32,
+    day: Option<u32>,
+}
+
+impl DateFilter {
+    pub fn matches(&self, date: Date) -> bool {
+        date.year == self.year && self.day.is_none_or(|day| date.day == day)
+    }
+}
+
+/// A day's entry point, in whichever form of the input it wants
+#[derive(Copy, Clone)]
+pub enum SolutionFn {
+    Text(fn(&str) -> SolutionResult),
+    Bytes(fn(&[u8]) -> SolutionResult),
+    /// A `Phased` day, via `run_phased`
+    Phased(fn(&str) -> (SolutionResult, Phases)),
+    /// A day that reports bad or unsolvable input instead of panicking
+    Fallible(fn(&str) -> Result<SolutionResult, SolveError>),
+    /// A fallible day that can be tuned with `Params`
+    Params(fn(&str, &Params) -> Result<SolutionResult, SolveError>),
+}
+
+/// Why a solution couldn't produce its answers
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SolveError {
+    /// The input isn't in the puzzle's format.  Lines and columns count from 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed but the solution found no answer for it
+    Unsolvable(String),
+    /// The input is well-formed but relies on something the solution doesn't handle
+    Unsupported(String),
+    /// A parameter's value couldn't be used
+    Param { name: String, message: String },
+}
+
+impl SolveError {
+    /// A parse error at `token`, which must be a slice of `line`, the input's line number
+    /// `line_number`
+    pub fn parse_at(
+        line_number: usize,
+        line: &str,
+        token: &str,
+        message: impl Display,
+    ) -> SolveError {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        debug_assert!(
+            offset <= line.len() && offset + token.len() <= line.len(),
+            "{token:?} isn't a slice of {line:?}"
+        );
+        SolveError::Parse {
+            line: line_number,
+            column: line[..offset].chars().count() + 1,
+            message: format!("{message}: {token:?}"),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            SolveError::Unsolvable(message) => write!(f, "no solution: {message}"),
+            SolveError::Unsupported(message) => write!(f, "unsupported input: {message}"),
+            SolveError::Param { name, message } => {
+                write!(f, "invalid parameter {name}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// A day split into phases that are timed separately: parsing the input, then each part
+/// working from what was parsed
+pub trait Phased {
+    type Parsed;
+
+    fn parse(input: &str) -> Self::Parsed;
+    fn part1(parsed: &Self::Parsed) -> impl Into<Answer>;
+    fn part2(parsed: &Self::Parsed) -> impl Into<Answer>;
+}
+
+/// How long each phase of a `Phased` day took
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Phases {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+pub fn run_phased<T: Phased>(input: &str) -> (SolutionResult, Phases) {
+    let start = Instant::now();
+    let parsed = T::parse(input);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let a = T::part1(&parsed).into();
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    let b = T::part2(&parsed).into();
+    let part2 = start.elapsed();
+
+    (
+        SolutionResult { a, b },
+        Phases {
+            parse,
+            part1,
+            part2,
+        },
+    )
+}
+
+#[derive(Clone)]
+pub struct Solution {
+    function: SolutionFn,
+    label: String,
+}
+
+impl Solution {
+    pub fn new(function: SolutionFn, label: &str) -> Solution {
+        Solution {
+            function,
+            label: label.to_string(),
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn run(&self, input: &Input) -> Result<SolutionResult, SolveError> {
+        Ok(self.run_phases(input)?.0)
+    }
+
+    /// Run the solution, also timing each phase if it's a `Phased` day
+    pub fn run_phases(
+        &self,
+        input: &Input,
+    ) -> Result<(SolutionResult, Option<Phases>), SolveError> {
+        match self.function {
+            SolutionFn::Text(function) => Ok((function(input.as_str()), None)),
+            SolutionFn::Bytes(function) => Ok((function(input.as_bytes()), None)),
+            SolutionFn::Phased(function) => {
+                let (result, phases) = function(input.as_str());
+                Ok((result, Some(phases)))
+            }
+            SolutionFn::Fallible(function) => Ok((function(input.as_str())?, None)),
+            SolutionFn::Params(function) => Ok((function(input.as_str(), input.params())?, None)),
+        }
+    }
+}
+
+/// Every implemented solution, keyed by the puzzle's date
+#[derive(Clone, Default)]
+pub struct Registry {
+    solutions: BTreeMap<Date, Solution>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn register(&mut self, date: Date, solution: Solution) {
+        if self.solutions.insert(date, solution).is_some() {
+            panic!("{date} registered more than once");
+        }
+    }
+
+    pub fn extend(&mut self, solutions: impl IntoIterator<Item = (Date, Solution)>) {
+        for (date, solution) in solutions {
+            self.register(date, solution);
+        }
+    }
+
+    pub fn get(&self, date: Date) -> Option<&Solution> {
+        self.solutions.get(&date)
+    }
+
+    pub fn dates(&self) -> impl Iterator<Item = Date> + '_ {
+        self.solutions.keys().copied()
+    }
+
+    /// All solutions in date order
+    pub fn iter(&self) -> impl Iterator<Item = (Date, &Solution)> {
+        self.solutions
+            .iter()
+            .map(|(date, solution)| (*date, solution))
+    }
+
+    /// Solutions in date order, restricted to `filter` if there is one
+    pub fn select(&self, filter: Option<DateFilter>) -> impl Iterator<Item = (Date, &Solution)> {
+        self.iter()
+            .filter(move |(date, _)| filter.is_none_or(|filter| filter.matches(*date)))
+    }
+}
+
+/// Define a year module's `solutions()` function, which lists every day implemented in that
+/// module.  Each day's function is `dayNN::dayNN`, taking `&str` unless another `SolutionFn`
+/// variant is named.  A `Phased` day names its `Phased` type instead.
+///
+/// ```ignore
+/// register_solutions! {
+///     2024;
+///     1 => day01,
+///     4 => day04: Bytes,
+///     22 => day22: Phased<Day22>,
+/// }
+/// ```
+#[macro_export]
+macro_rules! register_solutions {
+    ($year:literal; $($day:literal => $module:ident $(: $kind:ident $(<$type:ident>)?)?),* $(,)?) => {
+        pub fn solutions() -> Vec<($crate::aoc::Date, $crate::aoc::Solution)> {
+            vec![
+                $((
+                    $crate::aoc::Date::new($year, $day),
+                    $crate::aoc::Solution::new(
+                        $crate::register_solutions!(@function $module $($kind $($type)?)?),
+                        concat!(module_path!(), "::", stringify!($module)),
+                    ),
+                )),*
+            ]
+        }
+    };
+    (@function $module:ident) => {
+        $crate::aoc::SolutionFn::Text($module::$module)
+    };
+    (@function $module:ident Phased $type:ident) => {
+        $crate::aoc::SolutionFn::Phased($crate::aoc::run_phased::<$module::$type>)
+    };
+    (@function $module:ident $kind:ident) => {
+        $crate::aoc::SolutionFn::$kind($module::$module)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_paths_resolve_variants() {
+        let datadir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let names = |paths: Vec<PathBuf>| -> Vec<String> {
+            paths
+                .iter()
+                .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+                .collect()
+        };
+        assert_eq!(
+            names(Date::new(2024, 10).example_paths(&datadir)),
+            ["10example1.txt", "10example2.txt", "10example3.txt"]
+        );
+        assert_eq!(
+            names(Date::new(2024, 2).example_paths(&datadir)),
+            ["02example.txt", "02test.txt"]
+        );
+        assert_eq!(
+            Date::new(2024, 1).example_path(&datadir, 1),
+            Some(datadir.join("2024/01example.txt"))
+        );
+        assert_eq!(
+            Date::new(2024, 10).example_path(&datadir, 2),
+            Some(datadir.join("2024/10example2.txt"))
+        );
+        assert_eq!(Date::new(2024, 1).example_path(&datadir, 2), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "isn't a slice of")]
+    fn parse_at_requires_a_token_in_the_line() {
+        let line = "1,2";
+        SolveError::parse_at(1, line, "2", "bad number");
+    }
+
+    #[test]
+    fn values_match_their_written_form() {
+        assert!(Value::from(42u64).matches("042"));
+        assert!(!Value::from(42).matches("42.5"));
+        assert!(Value::from(vec![6, 7, 5]).matches("6,7,5"));
+        assert!(!Value::from(vec![6, 7]).matches("6,7,5"));
+        assert!(Value::from((24, 48)).matches("24, 48"));
+        assert!(Value::from("ar,cd").matches("ar,cd"));
+        assert_eq!(Value::from(vec!["ar", "cd"]).to_string(), "ar,cd");
+    }
+}
@@ -0,0 +1,292 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Timings of each solution and the report of a run, for people or for other programs
+
+use super::{Date, Phases, SolutionResult, Value, Verdict};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// How long each of several runs of a solution took
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    runs: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn new(runs: Vec<Duration>) -> Timings {
+        Timings { runs }
+    }
+
+    pub fn runs(&self) -> &[Duration] {
+        &self.runs
+    }
+
+    pub fn min(&self) -> Duration {
+        self.runs.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut runs = self.runs.clone();
+        runs.sort();
+        match runs.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 0 => (runs[n / 2 - 1] + runs[n / 2]) / 2,
+            n => runs[n / 2],
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        match self.runs.len() {
+            0 => Duration::ZERO,
+            n => self.runs.iter().sum::<Duration>() / n as u32,
+        }
+    }
+
+    /// Population standard deviation
+    pub fn stddev(&self) -> Duration {
+        if self.runs.is_empty() {
+            return Duration::ZERO;
+        }
+        let mean = self.mean().as_secs_f64();
+        let variance = self
+            .runs
+            .iter()
+            .map(|run| (run.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / self.runs.len() as f64;
+        Duration::from_secs_f64(variance.sqrt())
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.runs.as_slice() {
+            [run] => write!(f, "{run:?}"),
+            runs => write!(
+                f,
+                "min {:?}, median {:?}, mean {:?}, stddev {:?} ({} runs)",
+                self.min(),
+                self.median(),
+                self.mean(),
+                self.stddev(),
+                runs.len()
+            ),
+        }
+    }
+}
+
+/// Timings of each phase of a `Phased` day over several runs
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PhaseTimings {
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+impl PhaseTimings {
+    pub fn new(runs: &[Phases]) -> PhaseTimings {
+        let timings =
+            |phase: fn(&Phases) -> Duration| Timings::new(runs.iter().map(phase).collect());
+        PhaseTimings {
+            parse: timings(|phases| phases.parse),
+            part1: timings(|phases| phases.part1),
+            part2: timings(|phases| phases.part2),
+        }
+    }
+}
+
+/// Everything learned from running one solution on one input
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub date: Date,
+    /// The input file's name without its extension, e.g. `07` or `07example`
+    pub input: String,
+    pub result: SolutionResult,
+    /// Only when verifying
+    pub verdicts: Option<[Verdict; 2]>,
+    pub timings: Timings,
+    pub phase_timings: Option<PhaseTimings>,
+}
+
+/// A `Report` flattened for JSON and CSV, with times in nanoseconds
+#[derive(Clone, Debug, Serialize)]
+pub struct ReportRecord {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    /// Only if the part has an answer; otherwise its state says why not
+    pub part1: Option<Value>,
+    pub part2: Option<Value>,
+    pub part1_state: &'static str,
+    pub part2_state: &'static str,
+    pub part1_status: Option<&'static str>,
+    pub part2_status: Option<&'static str>,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+    /// Median time of each phase of a `Phased` day
+    pub parse_ns: Option<u64>,
+    pub part1_ns: Option<u64>,
+    pub part2_ns: Option<u64>,
+}
+
+impl ReportRecord {
+    pub const CSV_HEADER: &str = "year,day,input,part1,part2,part1_state,part2_state,\
+        part1_status,part2_status,runs,\
+        min_ns,median_ns,mean_ns,stddev_ns,parse_ns,part1_ns,part2_ns";
+
+    /// One CSV row, in the column order of `CSV_HEADER`
+    pub fn csv_row(&self) -> String {
+        let optional = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+        let answer = |value: &Option<Value>| {
+            csv_field(&value.as_ref().map(Value::to_string).unwrap_or_default())
+        };
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            csv_field(&self.input),
+            answer(&self.part1),
+            answer(&self.part2),
+            self.part1_state.to_string(),
+            self.part2_state.to_string(),
+            self.part1_status.unwrap_or_default().to_string(),
+            self.part2_status.unwrap_or_default().to_string(),
+            self.runs.to_string(),
+            self.min_ns.to_string(),
+            self.median_ns.to_string(),
+            self.mean_ns.to_string(),
+            self.stddev_ns.to_string(),
+            optional(self.parse_ns),
+            optional(self.part1_ns),
+            optional(self.part2_ns),
+        ]
+        .join(",")
+    }
+}
+
+/// Quote a CSV field if it needs it
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub(super) fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Report {
+    pub fn record(&self) -> ReportRecord {
+        let status = |part: usize| {
+            self.verdicts
+                .as_ref()
+                .map(|verdicts| verdicts[part].status())
+        };
+        let phase = |timings: fn(&PhaseTimings) -> &Timings| {
+            self.phase_timings
+                .as_ref()
+                .map(|phase_timings| nanos(timings(phase_timings).median()))
+        };
+        ReportRecord {
+            year: self.date.year(),
+            day: self.date.day(),
+            input: self.input.clone(),
+            part1: self.result.a.value().cloned(),
+            part2: self.result.b.value().cloned(),
+            part1_state: self.result.a.state(),
+            part2_state: self.result.b.state(),
+            part1_status: status(0),
+            part2_status: status(1),
+            runs: self.timings.runs().len(),
+            min_ns: nanos(self.timings.min()),
+            median_ns: nanos(self.timings.median()),
+            mean_ns: nanos(self.timings.mean()),
+            stddev_ns: nanos(self.timings.stddev()),
+            parse_ns: phase(|phase_timings| &phase_timings.parse),
+            part1_ns: phase(|phase_timings| &phase_timings.part1),
+            part2_ns: phase(|phase_timings| &phase_timings.part2),
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.verdicts.as_ref().is_some_and(|verdicts| {
+            verdicts
+                .iter()
+                .any(|verdict| matches!(verdict, Verdict::Fail { .. }))
+        })
+    }
+
+    /// Print the report for people to read.  Example inputs are named, since a day may have
+    /// several.
+    pub fn print_text(&self, example: bool) {
+        let label = match example {
+            true => format!("{} ({})", self.date, self.input),
+            false => self.date.to_string(),
+        };
+        match &self.verdicts {
+            Some([a, b]) => {
+                println!("{label} Part 1: {} {a}", self.result.a);
+                println!("{label} Part 2: {} {b}", self.result.b);
+            }
+            None => {
+                println!("{label} Part 1: {}", self.result.a);
+                println!("{label} Part 2: {}", self.result.b);
+            }
+        }
+        println!("Time: {}", self.timings);
+        if let Some(phase_timings) = &self.phase_timings {
+            println!("  Parse: {}", phase_timings.parse);
+            println!("  Part 1: {}", phase_timings.part1);
+            println!("  Part 2: {}", phase_timings.part2);
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Answers and timings for people to read
+    #[default]
+    Text,
+    /// A JSON array with one object per solution and input
+    Json,
+    /// A CSV header, then one row per solution and input
+    Csv,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timings_statistics() {
+        let timings = Timings::new(
+            [4, 1, 3, 2]
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+        );
+        assert_eq!(timings.min(), Duration::from_millis(1));
+        assert_eq!(timings.median(), Duration::from_micros(2500));
+        assert_eq!(timings.mean(), Duration::from_micros(2500));
+        let stddev = timings.stddev().as_secs_f64();
+        assert!((stddev - 1.25e-6f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("6,7,5"), "\"6,7,5\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("1151"), "1151");
+    }
+}
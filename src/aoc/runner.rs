@@ -0,0 +1,527 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! The command line: choosing solutions and inputs, running them and reporting the results
+
+use super::{
+    find_data_dir, parse_param, scaffold, Answers, Date, DateFilter, Format, History, HistoryEntry,
+    Input, Params, PhaseTimings, Registry, Report, ReportRecord, Solution, SolutionResult,
+    SolveError, Timings,
+};
+use clap::{Parser, Subcommand};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Advent of Code solutions")]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<Commands>,
+    /// Run only a single year's solutions
+    #[arg(short, long)]
+    year: Option<u32>,
+    /// Run only a single day's solution (requires --year)
+    #[arg(short, long, requires = "year", value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: Option<u32>,
+    /// Input data directory (excludes --inputfile) [default: $AOC_DATA_DIR, or the nearest
+    /// `data` directory in or above the current directory]
+    #[arg(long, conflicts_with = "inputfile")]
+    datadir: Option<PathBuf>,
+    /// Input file (requires --day, excludes --datadir)
+    #[arg(short, long, requires = "day")]
+    inputfile: Option<PathBuf>,
+    /// Run example N instead of the real input, or every example if N is omitted
+    /// (excludes --inputfile)
+    #[arg(short, long, value_name = "N", num_args = 0..=1, conflicts_with = "inputfile")]
+    example: Option<Option<u32>>,
+    /// Check each answer against the input's `.answers` file
+    #[arg(long)]
+    verify: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Override one of the solutions' parameters; may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Run solutions at the same time on all cores; leave this off when comparing individual
+    /// solutions' timings
+    #[arg(short, long)]
+    parallel: bool,
+    /// Append this run's timings to the data directory's history.csv (excludes --inputfile)
+    #[arg(long, conflicts_with = "inputfile")]
+    record: bool,
+    /// Compare this run's timings with the latest recorded ones (excludes --inputfile)
+    #[arg(long, conflicts_with = "inputfile")]
+    compare: bool,
+    /// With --compare, flag solutions whose median time grew by more than this percentage
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 10.0,
+        requires = "compare"
+    )]
+    threshold: f64,
+    /// Run each solution up to this many times, reporting timing statistics
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+    /// Stop repeating a solution once this many seconds have passed
+    #[arg(short, long, default_value_t = 1)]
+    seconds: u32,
+    /// Run the day's examples and real input, then run them again whenever one of its input
+    /// files changes (requires --day, excludes --inputfile, --example, --record and
+    /// --compare).  Changes to the solution itself still need a rebuild.
+    #[arg(
+        short,
+        long,
+        requires = "day",
+        conflicts_with_all = ["inputfile", "example", "record", "compare"]
+    )]
+    watch: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Create a day's module from a template, register it, and create empty input, example
+    /// and answers files for it.  A new year's module is created and registered too.
+    New {
+        year: u32,
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+}
+
+/// Which example inputs to run in place of the real ones
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Example {
+    All,
+    Number(u32),
+}
+
+#[derive(Debug)]
+pub struct RunnerOptions {
+    pub inputfile: Option<PathBuf>,
+    pub datadir: Option<PathBuf>,
+    pub example: Option<Example>,
+    pub verify: bool,
+    pub format: Format,
+    pub params: Params,
+    pub parallel: bool,
+    pub record: bool,
+    pub compare: bool,
+    pub threshold: f64,
+    pub dates: Option<DateFilter>,
+    pub repeat: u32,
+    pub seconds: u32,
+    pub watch: bool,
+    /// Scaffold this day instead of running anything
+    pub new: Option<Date>,
+}
+
+impl RunnerOptions {
+    pub fn process_args() -> RunnerOptions {
+        RunnerOptions::from_args(Args::parse())
+    }
+
+    pub fn from_args(args: Args) -> RunnerOptions {
+        RunnerOptions {
+            inputfile: args.inputfile,
+            datadir: args.datadir,
+            example: args
+                .example
+                .map(|example| example.map_or(Example::All, Example::Number)),
+            verify: args.verify,
+            format: args.format,
+            params: {
+                let mut params = Params::default();
+                for (name, value) in &args.params {
+                    params.set(name, value);
+                }
+                params
+            },
+            parallel: args.parallel,
+            record: args.record,
+            compare: args.compare,
+            threshold: args.threshold,
+            dates: args.year.map(|year| DateFilter {
+                year,
+                day: args.day,
+            }),
+            repeat: args.repeat,
+            seconds: args.seconds,
+            watch: args.watch,
+            new: args
+                .command
+                .map(|Commands::New { year, day }| Date::new(year, day)),
+        }
+    }
+
+    /// Run every registered solution selected by these options, printing the answers.
+    /// Returns failure if no solution is selected, the data directory can't be found, any
+    /// selected solution's input is missing or could not be read, any solution fails, the
+    /// history can't be read or written, or when verifying, any answer is wrong.
+    pub fn run(&self, registry: &Registry) -> ExitCode {
+        if let Some(date) = self.new {
+            return self.new_day(registry, date);
+        }
+
+        let datadir = match &self.inputfile {
+            Some(_) => None,
+            None => match find_data_dir(self.datadir.as_deref()) {
+                Ok(datadir) => Some(datadir),
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+
+        if self.watch {
+            let datadir = datadir.expect("--watch excludes --inputfile");
+            return self.watch(registry, &datadir);
+        }
+
+        let selected: Vec<(Date, &Solution)> = registry.select(self.dates).collect();
+        if selected.is_empty() {
+            eprintln!("no solutions selected; is the year's feature enabled?");
+            return ExitCode::FAILURE;
+        }
+
+        let mut exit_code = ExitCode::SUCCESS;
+        let mut jobs = Vec::new();
+        for (date, solution) in selected {
+            let paths = match (&self.inputfile, &datadir) {
+                (Some(inputfile), _) => vec![inputfile.clone()],
+                (None, Some(datadir)) => match self.example {
+                    None => vec![date.input_path(datadir)],
+                    Some(Example::All) => date.example_paths(datadir),
+                    Some(Example::Number(n)) => date.example_path(datadir, n).into_iter().collect(),
+                },
+                (None, None) => unreachable!(),
+            };
+            if paths.is_empty() {
+                match self.example {
+                    Some(Example::Number(n)) => eprintln!("{date}: no example {n}"),
+                    _ => eprintln!("{date}: no examples"),
+                }
+                exit_code = ExitCode::FAILURE;
+            }
+
+            jobs.extend(paths.into_iter().map(|path| (date, solution, path)));
+        }
+
+        let history_path = datadir.as_deref().map(History::path);
+        let history = match (&history_path, self.compare) {
+            (Some(history_path), true) => match History::load(history_path) {
+                Ok(history) => Some(history),
+                Err(err) => {
+                    eprintln!("failed to read {}: {err}", history_path.display());
+                    return ExitCode::FAILURE;
+                }
+            },
+            _ => None,
+        };
+
+        if self.format == Format::Csv {
+            println!("{}", ReportRecord::CSV_HEADER);
+        }
+        let start = Instant::now();
+        let mut reports = Vec::new();
+        let mut report = |report: Result<Report, String>| {
+            let report = match report {
+                Ok(report) => report,
+                Err(err) => {
+                    eprintln!("{err}");
+                    exit_code = ExitCode::FAILURE;
+                    return;
+                }
+            };
+            if report.failed() {
+                exit_code = ExitCode::FAILURE;
+            }
+            match self.format {
+                Format::Text => report.print_text(self.example.is_some()),
+                Format::Json => {}
+                Format::Csv => println!("{}", report.record().csv_row()),
+            }
+            reports.push(report);
+        };
+        if self.parallel {
+            let results: Vec<_> = jobs
+                .par_iter()
+                .map(|(date, solution, path)| self.run_one(*date, solution, path))
+                .collect();
+            results.into_iter().for_each(report);
+        } else {
+            for (date, solution, path) in &jobs {
+                report(self.run_one(*date, solution, path));
+            }
+        }
+        let elapsed = start.elapsed();
+
+        match self.format {
+            Format::Text if reports.len() > 1 => print_summary(&reports, elapsed),
+            Format::Json => {
+                let records: Vec<ReportRecord> = reports.iter().map(Report::record).collect();
+                println!("{}", serde_json::to_string_pretty(&records).unwrap());
+            }
+            _ => {}
+        }
+
+        if let Some(history) = history {
+            self.print_comparison(&history, &reports);
+        }
+        if let (Some(history_path), true) = (&history_path, self.record) {
+            let entries = HistoryEntry::from_reports(&reports);
+            if let Err(err) = History::append(history_path, &entries) {
+                eprintln!("failed to write {}: {err}", history_path.display());
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+        exit_code
+    }
+
+    /// Scaffold a day's solution and data files, listing the files created or changed
+    fn new_day(&self, registry: &Registry, date: Date) -> ExitCode {
+        if registry.get(date).is_some() {
+            eprintln!("{date} already has a solution");
+            return ExitCode::FAILURE;
+        }
+        let datadir = match find_data_dir(self.datadir.as_deref()) {
+            Ok(datadir) => datadir,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let srcdir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        match scaffold(&srcdir, &datadir, date) {
+            Ok(changes) => {
+                for (path, change) in changes {
+                    println!("{change} {}", path.display());
+                }
+                println!("Rebuild to run {date}");
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        }
+    }
+
+    /// Run the selected day on its examples and real input, then poll the input files and run
+    /// it again whenever any of them is changed, added or removed, showing which answers
+    /// differ from the previous run.  Only returns if no solution is selected.
+    fn watch(&self, registry: &Registry, datadir: &Path) -> ExitCode {
+        let Some((date, solution)) = registry.select(self.dates).next() else {
+            eprintln!("no solution to watch");
+            return ExitCode::FAILURE;
+        };
+        let mut previous: BTreeMap<String, SolutionResult> = BTreeMap::new();
+        let mut last_modified = None;
+        loop {
+            let paths = date.watched_paths(datadir);
+            let modified: Vec<(PathBuf, Option<SystemTime>)> = paths
+                .iter()
+                .map(|path| {
+                    let modified = fs::metadata(path).and_then(|meta| meta.modified());
+                    (path.clone(), modified.ok())
+                })
+                .collect();
+            if last_modified.as_ref() != Some(&modified) {
+                last_modified = Some(modified);
+                println!("Running {date}");
+                for path in &paths {
+                    let report = match self.run_one(date, solution, path) {
+                        Ok(report) => report,
+                        Err(err) => {
+                            eprintln!("{err}");
+                            continue;
+                        }
+                    };
+                    report.print_text(true);
+                    if let Some(before) = previous.get(&report.input) {
+                        for line in answer_changes(before, &report.result) {
+                            println!("  {line}");
+                        }
+                    }
+                    previous.insert(report.input, report.result);
+                }
+                println!("Watching {} input files", paths.len());
+            }
+            thread::sleep(WATCH_INTERVAL);
+        }
+    }
+
+    /// Show how each solution's median time compares with the latest recorded one, flagging
+    /// those slower by more than the threshold.  Goes to stderr unless the output is text, so
+    /// as not to spoil JSON or CSV.
+    fn print_comparison(&self, history: &History, reports: &[Report]) {
+        let mut lines = Vec::new();
+        let mut slower = 0;
+        for report in reports {
+            let label = match self.example {
+                Some(_) => format!("{} ({})", report.date, report.input),
+                None => report.date.to_string(),
+            };
+            let median = report.timings.median();
+            let Some(previous) = history.latest(report.date, &report.input) else {
+                lines.push(format!("{label}: {median:.3?}, no history"));
+                continue;
+            };
+            let was = Duration::from_nanos(previous.median_ns);
+            let commit = match previous.commit.as_str() {
+                "" => String::new(),
+                commit => format!(" at {commit}"),
+            };
+            // A percentage change from nothing is meaningless
+            if was.is_zero() {
+                lines.push(format!(
+                    "{label}: {median:.3?}, was {was:.3?}{commit}, no comparison"
+                ));
+                continue;
+            }
+            let change = (median.as_secs_f64() / was.as_secs_f64() - 1.0) * 100.0;
+            let flag = if change > self.threshold {
+                slower += 1;
+                " SLOWER"
+            } else {
+                ""
+            };
+            lines.push(format!(
+                "{label}: {median:.3?}, was {was:.3?}{commit} ({change:+.1}%){flag}"
+            ));
+        }
+        lines.push(format!("{slower} slower by more than {}%", self.threshold));
+
+        for line in lines {
+            match self.format {
+                Format::Text => println!("{line}"),
+                Format::Json | Format::Csv => eprintln!("{line}"),
+            }
+        }
+    }
+
+    /// Run one solution on one input, reading the input's answers too if verifying.  Errors
+    /// are described ready to print.
+    fn run_one(&self, date: Date, solution: &Solution, path: &Path) -> Result<Report, String> {
+        let mut input = Input::load(path).map_err(|err| format!("{date}: {err}"))?;
+        input.params_mut().extend(&self.params);
+        let answers = match self.verify {
+            true => Some(Answers::load(path).map_err(|err| {
+                let answers_path = Answers::path(path);
+                format!("{date}: failed to read {}: {err}", answers_path.display())
+            })?),
+            false => None,
+        };
+
+        let (result, timings, phase_timings) = self
+            .time(solution, &input)
+            .map_err(|err| format!("{date}: {err}"))?;
+        Ok(Report {
+            date,
+            input: Self::input_name(path),
+            verdicts: answers.map(|answers| answers.verify(&result)),
+            result,
+            timings,
+            phase_timings,
+        })
+    }
+
+    /// Run a solution `repeat` times, or fewer if that would take more than `seconds`.  Every
+    /// run after the first is expected to give the same result.
+    fn time(
+        &self,
+        solution: &Solution,
+        input: &Input,
+    ) -> Result<(SolutionResult, Timings, Option<PhaseTimings>), SolveError> {
+        let deadline = Instant::now() + Duration::from_secs(self.seconds.into());
+        let mut runs = Vec::new();
+        let mut phase_runs = Vec::new();
+        loop {
+            let start = Instant::now();
+            let (result, phases) = solution.run_phases(input)?;
+            runs.push(start.elapsed());
+            phase_runs.extend(phases);
+            if runs.len() >= self.repeat as usize || Instant::now() >= deadline {
+                let phase_timings =
+                    (!phase_runs.is_empty()).then(|| PhaseTimings::new(&phase_runs));
+                return Ok((result, Timings::new(runs), phase_timings));
+            }
+        }
+    }
+
+    fn input_name(path: &Path) -> String {
+        path.file_stem()
+            .unwrap_or(path.as_os_str())
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// How often `--watch` checks whether the input files have changed
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Describe each part whose answer differs between two runs on the same input
+fn answer_changes(before: &SolutionResult, after: &SolutionResult) -> Vec<String> {
+    [(1, &before.a, &after.a), (2, &before.b, &after.b)]
+        .into_iter()
+        .filter(|(_, before, after)| before != after)
+        .map(|(part, before, after)| format!("Part {part} changed: {before} -> {after}"))
+        .collect()
+}
+
+/// Print the total of each year's median times, and of every year's.  The wall clock time
+/// is less than the total when solutions ran in parallel.
+fn print_summary(reports: &[Report], wall_clock: Duration) {
+    let mut years: BTreeMap<u32, (usize, Duration)> = BTreeMap::new();
+    for report in reports {
+        let (count, time) = years.entry(report.date.year()).or_default();
+        *count += 1;
+        *time += report.timings.median();
+    }
+    let total = reports
+        .iter()
+        .map(|report| report.timings.median())
+        .sum::<Duration>();
+
+    println!();
+    println!("{:<6} {:>6} {:>14}", "Year", "Inputs", "Time");
+    for (year, (count, time)) in years {
+        println!("{year:<6} {count:>6} {time:>14.3?}");
+    }
+    println!("{:<6} {:>6} {total:>14.3?}", "Total", reports.len());
+    println!("Wall clock: {wall_clock:.3?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::Answer;
+
+    #[test]
+    fn answer_changes_name_each_changed_part() {
+        let before = SolutionResult::new(12, 6);
+        assert!(answer_changes(&before, &before).is_empty());
+        let after = SolutionResult {
+            a: Answer::Value(14.into()),
+            b: Answer::NotImplemented,
+        };
+        assert_eq!(
+            answer_changes(&before, &after),
+            [
+                "Part 1 changed: 12 -> 14",
+                "Part 2 changed: 6 -> (not implemented)"
+            ]
+        );
+    }
+}
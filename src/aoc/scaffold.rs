@@ -0,0 +1,298 @@
+//
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Creating the files for a new day: `cargo run -- new <year> <day>`
+
+use super::Date;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day's module already exists, so it was left alone
+    Exists(PathBuf),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// A file that needed a line adding didn't have the expected layout
+    Layout {
+        path: PathBuf,
+        expected: String,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io { path, source } => {
+                write!(f, "failed to write {}: {source}", path.display())
+            }
+            ScaffoldError::Layout { path, expected } => {
+                write!(f, "{} has no {expected} to add to", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Create a day's module in `srcdir` and add it to its year's module, creating that and adding
+/// it to `lib.rs`, with a feature in `Cargo.toml`, if it's the year's first day.  Then create
+/// empty input, example and answers files for it in `datadir`, leaving any that already exist.
+/// Returns each file touched, with whether it was created or updated.
+///
+/// The writes aren't atomic, so a failure part way through leaves the files written so far.
+/// `Cargo.toml` is written first, where a feature for a year that didn't get its module is
+/// harmless.
+pub fn scaffold(
+    srcdir: &Path,
+    datadir: &Path,
+    date: Date,
+) -> Result<Vec<(PathBuf, &'static str)>, ScaffoldError> {
+    let (year, day) = (date.year(), date.day());
+    let yeardir = srcdir.join(format!("year{year}"));
+    let module_path = yeardir.join(format!("day{day:02}.rs"));
+    if module_path.exists() {
+        return Err(ScaffoldError::Exists(module_path));
+    }
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| ScaffoldError::Io { path, source }
+    };
+    let read = |path: &Path| fs::read_to_string(path).map_err(io_error(path));
+    let write = |path: &Path, text: &str| fs::write(path, text).map_err(io_error(path));
+
+    let mut changes = Vec::new();
+    let year_path = yeardir.join("mod.rs");
+    if year_path.exists() {
+        let text = read(&year_path)?;
+        let text = add_day_to_year_module(&text, day).ok_or_else(|| ScaffoldError::Layout {
+            path: year_path.clone(),
+            expected: "day modules or registered solutions".to_string(),
+        })?;
+        write(&year_path, &text)?;
+        changes.push((year_path, "updated"));
+    } else {
+        let lib_path = srcdir.join("lib.rs");
+        let lib = read(&lib_path)?;
+        let lib = add_year_to_lib(&lib, year).ok_or_else(|| ScaffoldError::Layout {
+            path: lib_path.clone(),
+            expected: "year modules or registry".to_string(),
+        })?;
+        let manifest_path = srcdir.with_file_name("Cargo.toml");
+        let manifest = read(&manifest_path)?;
+        let manifest =
+            add_year_to_manifest(&manifest, year).ok_or_else(|| ScaffoldError::Layout {
+                path: manifest_path.clone(),
+                expected: "year features".to_string(),
+            })?;
+        write(&manifest_path, &manifest)?;
+        changes.push((manifest_path, "updated"));
+        fs::create_dir_all(&yeardir).map_err(io_error(&yeardir))?;
+        write(&year_path, &year_module_template(year, day))?;
+        changes.push((year_path, "created"));
+        write(&lib_path, &lib)?;
+        changes.push((lib_path, "updated"));
+    }
+    write(&module_path, &day_module_template(year, day))?;
+    changes.push((module_path, "created"));
+
+    let data_yeardir = datadir.join(year.to_string());
+    fs::create_dir_all(&data_yeardir).map_err(io_error(&data_yeardir))?;
+    for name in ["", "example"] {
+        for extension in ["txt", "answers"] {
+            let path = data_yeardir.join(format!("{day:02}{name}.{extension}"));
+            if !path.exists() {
+                write(&path, "")?;
+                changes.push((path, "created"));
+            }
+        }
+    }
+    Ok(changes)
+}
+
+const LICENSE_HEADER: &str = "\
+//
+// Copyright (c) {year} David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+";
+
+fn license_header(year: u32) -> String {
+    LICENSE_HEADER.replace("{year}", &year.to_string())
+}
+
+fn day_module_template(year: u32, day: u32) -> String {
+    format!(
+        "{}
+use crate::aoc::*;
+
+pub fn day{day:02}(_input: &str) -> SolutionResult {{
+    SolutionResult {{
+        a: Answer::NotImplemented,
+        b: Answer::NotImplemented,
+    }}
+}}
+",
+        license_header(year)
+    )
+}
+
+fn year_module_template(year: u32, day: u32) -> String {
+    format!(
+        "{}
+pub mod day{day:02};
+
+crate::register_solutions! {{
+    {year};
+    {day} => day{day:02},
+}}
+",
+        license_header(year)
+    )
+}
+
+/// Declare and register a day in the text of its year's `mod.rs`
+fn add_day_to_year_module(text: &str, day: u32) -> Option<String> {
+    let text = insert_sorted(text, &[&format!("pub mod day{day:02};")], |line| {
+        line.strip_prefix("pub mod day")?
+            .strip_suffix(';')?
+            .parse::<u32>()
+            .ok()
+    })?;
+    insert_sorted(&text, &[&format!("    {day} => day{day:02},")], |line| {
+        line.trim_start().split_once(" =>")?.0.parse::<u32>().ok()
+    })
+}
+
+/// Declare a year's module in the text of `lib.rs` and add its solutions to the registry,
+/// both behind the year's feature
+fn add_year_to_lib(text: &str, year: u32) -> Option<String> {
+    let cfg = format!("#[cfg(feature = \"y{year}\")]");
+    let module = format!("pub mod year{year};");
+    let text = insert_sorted(text, &[&cfg, &module], |line| {
+        line.strip_prefix("pub mod year")?
+            .strip_suffix(';')?
+            .parse::<u32>()
+            .ok()
+    })?;
+    let cfg = format!("    {cfg}");
+    let extend = format!("    registry.extend(year{year}::solutions());");
+    insert_sorted(&text, &[&cfg, &extend], |line| {
+        let module = line.trim_start().strip_prefix("registry.extend(year")?;
+        module.split_once("::")?.0.parse::<u32>().ok()
+    })
+}
+
+/// Add a year's feature to the text of `Cargo.toml`, enabled by default
+fn add_year_to_manifest(text: &str, year: u32) -> Option<String> {
+    let feature = format!("\"y{year}\"");
+    let default_line = text.lines().find(|line| line.starts_with("default = ["))?;
+    let mut features: Vec<&str> = default_line
+        .strip_prefix("default = [")?
+        .strip_suffix(']')?
+        .split(", ")
+        .filter(|feature| !feature.is_empty())
+        .collect();
+    features.push(&feature);
+    features.sort();
+    let text = text.replacen(
+        default_line,
+        &format!("default = [{}]", features.join(", ")),
+        1,
+    );
+    insert_sorted(&text, &[&format!("y{year} = []")], |line| {
+        line.strip_prefix('y')?
+            .strip_suffix(" = []")?
+            .parse::<u32>()
+            .ok()
+    })
+}
+
+/// Insert `new_lines` among the lines that have a key, before the first with a greater key
+/// (and any attributes on it) or else after the last.  The new lines' key is that of the
+/// last of them.  `None` if no line has a key.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    new_lines: &[&str],
+    key: impl Fn(&str) -> Option<K>,
+) -> Option<String> {
+    let new_key = key(new_lines.last()?)?;
+    let mut lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, K)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, key(line)?)))
+        .collect();
+    let mut index = match keyed.iter().find(|(_, key)| *key > new_key) {
+        Some((index, _)) => *index,
+        None => keyed.last()?.0 + 1,
+    };
+    while index > 0 && lines[index - 1].trim_start().starts_with("#[") {
+        index -= 1;
+    }
+    lines.splice(index..index, new_lines.iter().copied());
+    Some(lines.iter().map(|line| format!("{line}\n")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaffolding_keeps_days_and_years_in_order() {
+        let year_module = "pub mod day01;\npub mod day03;\n\n\
+            crate::register_solutions! {\n    2025;\n    1 => day01,\n    3 => day03,\n}\n";
+        assert_eq!(
+            add_day_to_year_module(year_module, 2).unwrap(),
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\n\
+            crate::register_solutions! {\n    2025;\n    1 => day01,\n    2 => day02,\n    \
+            3 => day03,\n}\n"
+        );
+        assert_eq!(
+            add_day_to_year_module(year_module, 4).unwrap(),
+            "pub mod day01;\npub mod day03;\npub mod day04;\n\n\
+            crate::register_solutions! {\n    2025;\n    1 => day01,\n    3 => day03,\n    \
+            4 => day04,\n}\n"
+        );
+
+        let lib = "#[cfg(feature = \"y2024\")]\npub mod year2024;\n\n\
+            pub fn registry() -> Registry {\n    let mut registry = Registry::new();\n    \
+            #[cfg(feature = \"y2024\")]\n    registry.extend(year2024::solutions());\n    \
+            registry\n}\n";
+        assert_eq!(
+            add_year_to_lib(lib, 2023).unwrap(),
+            "#[cfg(feature = \"y2023\")]\npub mod year2023;\n\
+            #[cfg(feature = \"y2024\")]\npub mod year2024;\n\n\
+            pub fn registry() -> Registry {\n    let mut registry = Registry::new();\n    \
+            #[cfg(feature = \"y2023\")]\n    registry.extend(year2023::solutions());\n    \
+            #[cfg(feature = \"y2024\")]\n    registry.extend(year2024::solutions());\n    \
+            registry\n}\n"
+        );
+        assert_eq!(add_year_to_lib("", 2025), None);
+
+        let manifest = "[features]\ndefault = [\"y2024\"]\ny2024 = []\n\n[profile.release]\n";
+        assert_eq!(
+            add_year_to_manifest(manifest, 2025).unwrap(),
+            "[features]\ndefault = [\"y2024\", \"y2025\"]\ny2024 = []\ny2025 = []\n\n\
+            [profile.release]\n"
+        );
+    }
+}
@@ -5,18 +5,16 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 
 fn parse_input(input: &str) -> Vec<i32> {
     input
         .split_whitespace()
-        .map(|x| x.parse::<i32>())
-        .filter(|x| x.is_ok())
-        .map(|x| x.unwrap())
+        .flat_map(|x| x.parse::<i32>())
         .collect::<Vec<i32>>()
 }
 
-fn count_depth_increases(v: &Vec<i32>, window_size: usize) -> usize {
+fn count_depth_increases(v: &[i32], window_size: usize) -> usize {
     // Part B explanation: the problem asks us to compare the sums of the
     // elements of overlapping windows of size 3:
     //   (a + b + c) < (b + c + d)
@@ -6,3 +6,8 @@
 //
 
 pub mod day01;
+
+crate::register_solutions! {
+    2021;
+    1 => day01,
+}
@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 
 pub fn day01(input: &str) -> SolutionResult {
     let input_lines = input.split('\n');
@@ -23,9 +23,9 @@ pub fn day01(input: &str) -> SolutionResult {
     elf_calories.select_nth_unstable_by(2, |a,b| b.cmp(a));
     elf_calories.resize(3, 0);
 
-    let result = SolutionResult {
-        a: elf_calories.iter().max().unwrap().to_string(),
-        b: elf_calories.iter().sum::<i32>().to_string(),
-    };
+    let result = SolutionResult::new(
+        elf_calories.iter().max().unwrap(),
+        elf_calories.iter().sum::<i32>(),
+    );
     result
 }
\ No newline at end of file
@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 
 use itertools::Itertools;
 
@@ -59,8 +59,5 @@ pub fn day03(input: &str) -> SolutionResult {
         .map(priority_from_mask)
         .sum();
 
-    SolutionResult {
-        a: part1_sum.to_string(),
-        b: part2_sum.to_string(),
-    }
+    SolutionResult::new(part1_sum, part2_sum)
 }
@@ -5,15 +5,24 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 
 use regex::Regex;
 
-fn parse_line(line: &str) -> ((u8,u8),(u8,u8)) {
+type Pair = ((u8,u8),(u8,u8));
+
+fn parse_line(line_number: usize, line: &str) -> Result<Pair, SolveError> {
     let re = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
-    let captures = re.captures(line).unwrap();
-    ((captures[1].parse::<u8>().unwrap(), captures[2].parse::<u8>().unwrap()),
-     (captures[3].parse::<u8>().unwrap(), captures[4].parse::<u8>().unwrap()))
+    let captures = re.captures(line).ok_or_else(|| SolveError::Parse {
+        line: line_number,
+        column: 1,
+        message: format!("expected two ranges like \"2-4,6-8\": {line:?}"),
+    })?;
+    let section = |i: usize| {
+        let capture = captures.get(i).unwrap().as_str();
+        capture.parse::<u8>().map_err(|err| SolveError::parse_at(line_number, line, capture, err))
+    };
+    Ok(((section(1)?, section(2)?), (section(3)?, section(4)?)))
 }
 
 fn contains(a: (u8, u8), b: (u8, u8)) -> bool {
@@ -32,15 +41,41 @@ fn overlaps(range: ((u8,u8),(u8,u8))) -> bool {
     contains_end(range.0, range.1) || contains_end(range.1, range.0)
 }
 
-pub fn day04(input: &str) -> SolutionResult {
+pub fn day04(input: &str) -> Result<SolutionResult, SolveError> {
     let pairs =
         input.trim()
             .split('\n')
-            .map(parse_line)
-            .collect::<Vec<((u8,u8),(u8,u8))>>();
+            .enumerate()
+            .map(|(i, line)| parse_line(i + 1, line))
+            .collect::<Result<Vec<Pair>, SolveError>>()?;
+
+    Ok(SolutionResult::new(
+        pairs.iter().filter(|x| either_contains(**x)).count(),
+        pairs.iter().filter(|x| overlaps(**x)).count(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    SolutionResult {
-        a: pairs.iter().filter(|x| either_contains(**x)).count().to_string(),
-        b: pairs.iter().filter(|x| overlaps(**x)).count().to_string(),
+    #[test]
+    fn malformed_lines() {
+        assert_eq!(
+            day04("2-4,6-8\n2-3,4-5\n5-7,7-300\n"),
+            Err(SolveError::Parse {
+                line: 3,
+                column: 7,
+                message: "number too large to fit in target type: \"300\"".to_string(),
+            })
+        );
+        assert_eq!(
+            day04("2-4,6-8\n2-3;4-5\n"),
+            Err(SolveError::Parse {
+                line: 2,
+                column: 1,
+                message: "expected two ranges like \"2-4,6-8\": \"2-3;4-5\"".to_string(),
+            })
+        );
     }
 }
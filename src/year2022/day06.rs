@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 
 fn not_unique(window: &[u8]) -> bool {
     let mut mask: u64 = 0;
@@ -20,8 +20,5 @@ pub fn day06(input: &str) -> SolutionResult {
     let part1 = trimmed_bytes.windows(4).take_while(|x| not_unique(x)).count() + 4;
     let part2 = trimmed_bytes.windows(14).take_while(|x| not_unique(x)).count() + 14;
 
-    SolutionResult {
-        a: part1.to_string(),
-        b: part2.to_string(),
-    }
+    SolutionResult::new(part1, part2)
 }
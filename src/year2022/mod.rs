@@ -9,3 +9,11 @@ pub mod day01;
 pub mod day03;
 pub mod day04;
 pub mod day06;
+
+crate::register_solutions! {
+    2022;
+    1 => day01,
+    3 => day03,
+    4 => day04: Fallible,
+    6 => day06,
+}
@@ -5,15 +5,35 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
-use itertools::Itertools;
+use crate::aoc::*;
 use std::collections::HashMap;
 
-pub fn day01(input: &str) -> SolutionResult {
-    let (mut list1, mut list2): (Vec<_>, Vec<_>) = input
+fn parse_line(line_number: usize, line: &str) -> Result<(i32, i32), SolveError> {
+    let numbers = line
         .split_ascii_whitespace()
-        .map(|num| num.parse::<i32>().unwrap())
-        .tuples::<(_, _)>()
+        .map(|num| {
+            num.parse::<i32>()
+                .map_err(|err| SolveError::parse_at(line_number, line, num, err))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match numbers[..] {
+        [a, b] => Ok((a, b)),
+        _ => Err(SolveError::Parse {
+            line: line_number,
+            column: 1,
+            message: format!("expected two numbers, found {}", numbers.len()),
+        }),
+    }
+}
+
+pub fn day01(input: &str) -> Result<SolutionResult, SolveError> {
+    let (mut list1, mut list2): (Vec<_>, Vec<_>) = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_line(i + 1, line))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
         .unzip();
     list1.sort_unstable();
     list2.sort_unstable();
@@ -31,8 +51,30 @@ pub fn day01(input: &str) -> SolutionResult {
         .map(|i| i * *list2counts.get(i).unwrap_or(&0))
         .sum();
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
+    Ok(SolutionResult::new(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_lines() {
+        assert_eq!(
+            day01("3   4\n4   3\n2   x5\n"),
+            Err(SolveError::Parse {
+                line: 3,
+                column: 5,
+                message: "invalid digit found in string: \"x5\"".to_string(),
+            })
+        );
+        assert_eq!(
+            day01("3   4\n4\n"),
+            Err(SolveError::Parse {
+                line: 2,
+                column: 1,
+                message: "expected two numbers, found 1".to_string(),
+            })
+        );
     }
 }
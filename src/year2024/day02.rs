@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::SolutionResult;
 use itertools::Itertools;
 
 fn safe<'a, I>(report: I) -> bool
@@ -23,7 +23,7 @@ where
         })
 }
 
-fn almost_safe(report: &Vec<i32>) -> bool {
+fn almost_safe(report: &[i32]) -> bool {
     let mut modified_reports = (0..report.len())
         .map(|i| report.iter().take(i).chain(report.iter().skip(i + 1)));
     modified_reports.any(safe)
@@ -42,8 +42,5 @@ pub fn day02(input: &str) -> SolutionResult {
     let a = reports.iter().filter(|r| safe(r.iter())).count();
     let b = reports.iter().filter(|r| almost_safe(r)).count();
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult::new(a, b)
 }
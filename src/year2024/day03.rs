@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::SolutionResult;
 use regex::Regex;
 
 pub fn day03(input: &str) -> SolutionResult {
@@ -5,12 +5,12 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::SolutionResult;
 use itertools::Itertools;
 
 pub fn day04(input: &[u8]) -> SolutionResult {
     let lines_e = input
-        .split(|c| *c == b'\n' || *c == b'\r')
+        .split(|c| *c == b'\n')
         .map_into::<Vec<u8>>()
         .filter(|l| !l.is_empty())
         .collect::<Vec<_>>();
@@ -60,7 +60,6 @@ pub fn day04(input: &[u8]) -> SolutionResult {
             
             let flipped = flip_diagonal(&candidate, 3);
 
-            // print_lines(&candidate);
             if candidate == pattern { b += 1 }
             if reverse_lines(&candidate) == pattern { b += 1 }
             if flipped == pattern { b += 1 }
@@ -76,8 +75,8 @@ struct Vec2 {
     y: i32,
 }
 
-fn reverse_lines(lines: &Vec<Vec<u8>>) -> Vec<Vec<u8>> {
-    let mut lines_w = lines.clone();
+fn reverse_lines(lines: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut lines_w = lines.to_vec();
     lines_w.iter_mut().for_each(|l| l.reverse());
     lines_w
 }
@@ -89,7 +88,7 @@ fn flip_diagonal(lines: &[Vec<u8>], w: i32) -> Vec<Vec<u8>> {
     lines_s
 }
 
-fn diagonals_ne(lines_e: &Vec<Vec<u8>>, h: i32, w: i32) -> Vec<Vec<u8>> {
+fn diagonals_ne(lines_e: &[Vec<u8>], h: i32, w: i32) -> Vec<Vec<u8>> {
     let mut lines_ne: Vec<Vec<u8>> = Vec::new();
     for i in 0..(h * 2 - 1) {
         let mut line: Vec<u8> = Vec::new();
@@ -110,11 +109,3 @@ fn diagonals_ne(lines_e: &Vec<Vec<u8>>, h: i32, w: i32) -> Vec<Vec<u8>> {
 pub fn bytes_to_str(input: &[u8]) -> String {
     String::from_utf8_lossy(input).to_string()
 }
-
-#[allow(dead_code)]
-fn print_lines(lines: &Vec<Vec<u8>>) {
-    for l in lines {
-        println!("{:>}", bytes_to_str(l));
-    }
-    println!();
-}
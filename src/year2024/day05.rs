@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::SolutionResult;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
@@ -54,7 +54,7 @@ fn fix_update(update: &mut [i32], rules: &HashMap<i32, Vec<i32>>) {
 }
 
 pub fn day05(input: &str) -> SolutionResult {
-    let lines = input.split(|c| c == '\n' || c == '\r').collect_vec();
+    let lines = input.split('\n').collect_vec();
     let separator = lines.iter().position(|line| line.is_empty()).unwrap();
     let rule_lines = lines.iter().take(separator - 1);
     let update_lines = lines.iter().skip(separator).filter(|line| !line.is_empty());
@@ -5,33 +5,30 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, SolutionResult, SolveError, Vec2};
 use itertools::Itertools;
 use rayon::prelude::*;
 
-pub fn day06(input: &str) -> SolutionResult {
-    let grid = Grid::from_u8(input.as_bytes());
+pub fn day06(input: &str) -> Result<SolutionResult, SolveError> {
+    let grid = Grid::parse(input.as_bytes())?;
 
     let (grid_a, _) = simulate_guard(&grid, None);
 
-    let a = grid_a.data_slice().iter().filter(|&&c| c == b'X').count();
+    let a = grid_a.count(b'X');
 
     let b = grid_a
-        .area()
-        .all_points()
-        .filter(|p| grid_a[*p] == b'X')
+        .find_all(b'X')
         .collect_vec()
         .par_iter()
-        .filter(|(x, y)| simulate_guard(&grid, Some(Vec2::new(*x, *y))).1)
+        .filter(|&&p| simulate_guard(&grid, Some(p)).1)
         .count();
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
 
 fn simulate_guard(grid: &Grid<u8>, obstacle: Option<Vec2<isize>>) -> (Grid<u8>, bool) {
     let mut grid = grid.clone();
-    let start = grid.area().all_points().find(|p| grid[*p] == b'^').unwrap();
-    let mut pos = Vec2::new(start.0, start.1);
+    let mut pos = grid.find(b'^').unwrap();
 
     if let Some(obstacle) = obstacle {
         if pos != obstacle {
@@ -78,14 +75,3 @@ fn simulate_guard(grid: &Grid<u8>, obstacle: Option<Vec2<isize>>) -> (Grid<u8>,
 
     (grid, looped)
 }
-
-#[allow(dead_code)]
-fn print_grid(grid: &Grid<u8>) {
-    for y in 0..grid.height() {
-        for x in 0..grid.width() {
-            print!("{}", grid[(x, y)] as char);
-        }
-        println!();
-    }
-    println!();
-}
@@ -5,12 +5,12 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, SolutionResult, SolveError, Vec2};
 use itertools::Itertools;
 use std::collections::HashMap;
 
-pub fn day08(input: &str) -> SolutionResult {
-    let mut grid = Grid::from_u8(input.as_bytes());
+pub fn day08(input: &str) -> Result<SolutionResult, SolveError> {
+    let mut grid = Grid::parse(input.as_bytes())?;
     let mut antenna_coords: HashMap<u8, Vec<Vec2<isize>>> = HashMap::new();
     for (x, y) in grid.area().all_points() {
         let antenna = grid[(x, y)];
@@ -23,7 +23,7 @@ pub fn day08(input: &str) -> SolutionResult {
     }
 
     let mut grid_b = grid.clone();
-    for (_, coords) in &antenna_coords {
+    for coords in antenna_coords.values() {
         for (pos1, pos2) in coords.iter().tuple_combinations::<(_, _)>() {
             let delta = *pos1 - *pos2;
             // Place part one antinodes in the original grid
@@ -45,8 +45,8 @@ pub fn day08(input: &str) -> SolutionResult {
         }
     }
 
-    let a = grid.data_slice().iter().filter(|&&c| c == b'#').count();
-    let b = grid_b.data_slice().iter().filter(|&&c| c == b'#').count();
+    let a = grid.count(b'#');
+    let b = grid_b.count(b'#');
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
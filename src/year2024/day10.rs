@@ -5,21 +5,12 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, SolutionResult, SolveError, Vec2};
 use itertools::Itertools;
 
-const CARDINALS: [(isize, isize); 4] = [
-    (0isize, 1isize),
-    (1isize, 0isize),
-    (0isize, -1isize),
-    (-1isize, 0isize),
-];
-
 pub fn valid_next_steps(grid: &Grid<u8>, pos: Vec2<isize>) -> Vec<Vec2<isize>> {
-    CARDINALS
-        .map(|c| pos + c)
-        .into_iter()
-        .filter(|p| grid.area().contains(*p) && grid[*p] == grid[pos] + 1)
+    grid.neighbours(pos)
+        .filter(|p| grid[*p] == grid[pos] + 1)
         .collect()
 }
 
@@ -49,23 +40,17 @@ pub fn trailhead_rating(grid: &Grid<u8>, trailhead: Vec2<isize>) -> usize {
         .sum()
 }
 
-pub fn day10(input: &str) -> SolutionResult {
-    let mut grid = Grid::from_u8(input.as_bytes());
-    for p in grid.area().all_points() {
-        if (grid[p] != b'.') {
-            grid[p] -= b'0';
-        }
-    }
+pub fn day10(input: &str) -> Result<SolutionResult, SolveError> {
+    // Heights become 0 to 9, leaving impassable '.' tiles well above them
+    let grid = Grid::parse_with(input.as_bytes(), |c| match c {
+        b'.' => c,
+        _ => c - b'0',
+    })?;
 
-    let trailheads = grid
-        .area()
-        .all_points()
-        .filter(|p| grid[*p] == 0)
-        .map(Vec2::from_tuple)
-        .collect_vec();
+    let trailheads = grid.find_all(0).collect_vec();
 
     let a: usize = trailheads.iter().map(|p| trailhead_score(&grid, *p)).sum();
     let b: usize = trailheads.iter().map(|p| trailhead_rating(&grid, *p)).sum();
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
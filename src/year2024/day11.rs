@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::SolutionResult;
 use std::collections::HashMap;
 
 pub fn blink(stones: &HashMap<i64, i64>) -> HashMap<i64, i64> {
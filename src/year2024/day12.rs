@@ -6,7 +6,7 @@
 //
 
 use std::collections::HashSet;
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, SolutionResult, SolveError, Vec2, CARDINALS};
 
 #[derive(Default, Debug)]
 struct Region {
@@ -16,15 +16,8 @@ struct Region {
     edge_segments: HashSet<(Vec2<isize>, Vec2<isize>)>,
 }
 
-const CARDINALS: [(isize, isize); 4] = [
-    (-1isize, 0isize),
-    (0isize, -1isize),
-    (1isize, 0isize),
-    (0isize, 1isize),
-];
-
-pub fn day12(input: &str) -> SolutionResult {
-    let input_grid = Grid::from_u8(input.as_bytes());
+pub fn day12(input: &str) -> Result<SolutionResult, SolveError> {
+    let input_grid = Grid::parse(input.as_bytes())?;
     let mut region_id_grid = Grid::<i32>::new(input_grid.width(), input_grid.height());
     region_id_grid.data_mut_slice().fill(-1);
 
@@ -52,7 +45,7 @@ pub fn day12(input: &str) -> SolutionResult {
         region.area += 1;
         for pos2 in CARDINALS {
             let neighbor = Vec2::from_tuple(pos) + pos2;
-            if !region_id_grid.area().contains(neighbor) || region_id_grid[neighbor] != id {
+            if region_id_grid.get(neighbor) != Some(&id) {
                 region.perimeter += 1;
                 region.edge_segments.insert((Vec2::from_tuple(pos), neighbor));
             }
@@ -94,7 +87,7 @@ pub fn day12(input: &str) -> SolutionResult {
         .map(|region| region.area * region.side_count)
         .sum::<usize>();
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
 
 fn flood_fill_region(
@@ -104,7 +97,7 @@ fn flood_fill_region(
     plant: u8,
     id: i32,
 ) {
-    if !input_grid.area().contains(pos) || input_grid[pos] != plant || region_id_grid[pos] == id {
+    if input_grid.get(pos) != Some(&plant) || region_id_grid[pos] == id {
         return;
     }
     region_id_grid[pos] = id;
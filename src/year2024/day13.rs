@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{SolutionResult, Vec2};
+use crate::aoc::{SolutionResult, Vec2};
 use itertools::Itertools;
 
 type SixNums = (f64, f64, f64, f64, f64, f64);
@@ -5,13 +5,13 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{Grid, Rect, SolutionResult, Vec2};
+use crate::aoc::{Params, Rect, SolutionResult, SolveError, Vec2};
 use itertools::Itertools;
 use std::hash::{DefaultHasher, Hash, Hasher};
 
 type FourNums = (i64, i64, i64, i64);
 
-pub fn day14(input: &str) -> SolutionResult {
+pub fn day14(input: &str, params: &Params) -> Result<SolutionResult, SolveError> {
     let robots = input
         .split(|c: char| !c.is_ascii_digit() && (c != '-'))
         .filter(|s| !s.is_empty())
@@ -20,11 +20,7 @@ pub fn day14(input: &str) -> SolutionResult {
         .map(|n| parse_robot(&n))
         .collect_vec();
 
-    let grid_dimensions = if robots.len() == 12 {
-        Vec2::new(11, 7)
-    } else {
-        Vec2::new(101, 103)
-    };
+    let grid_dimensions = Vec2::new(params.get("width", 101)?, params.get("height", 103)?);
 
     let mut robots_a = robots.clone();
 
@@ -75,7 +71,7 @@ pub fn day14(input: &str) -> SolutionResult {
         }
     }
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
 
 fn hash_robots(robots_b: &Vec<Robot>) -> u64 {
@@ -122,26 +118,3 @@ fn wrap_pos(mut pos: Vec2<i64>, dim: Vec2<i64>) -> Vec2<i64> {
     pos.y = ((pos.y % dim.y) + dim.y) % dim.y;
     pos
 }
-
-#[allow(dead_code)]
-fn print_grid(robots: &[Robot], dim: Vec2<i64>) {
-    let mut grid = Grid::<i32>::new(dim.x as isize, dim.y as isize);
-    for robot in robots.iter() {
-        grid[(robot.pos.x as isize, robot.pos.y as isize)] += 1;
-    }
-
-    for y in 0..dim.y {
-        for x in 0..dim.x {
-            let tile = grid[(x as isize, y as isize)];
-            if tile == 0 {
-                print!(".");
-            } else if tile > 9 {
-                print!("*");
-            } else {
-                print!("{}", tile);
-            }
-        }
-        println!();
-    }
-    println!();
-}
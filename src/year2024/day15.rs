@@ -6,9 +6,9 @@
 //
 
 use itertools::Itertools;
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, SolutionResult, SolveError, Vec2};
 
-pub fn day15(input: &str) -> SolutionResult {
+pub fn day15(input: &str) -> Result<SolutionResult, SolveError> {
     let first_move = input
         .as_bytes()
         .iter()
@@ -16,25 +16,20 @@ pub fn day15(input: &str) -> SolutionResult {
         .unwrap();
     let (input_grid, moves) = input.as_bytes().split_at(first_move);
     let moves = moves.iter().copied().filter(|x| b"^v><".contains(x)).collect_vec();
-    let mut grid_a = Grid::from_u8(input_grid);
+    let mut grid_a = Grid::parse(input_grid)?;
     let mut grid_b = widen_grid(&grid_a);
 
     part1_run(&moves, &mut grid_a);
-    let a = grid_a.area().all_points().filter(|&point| grid_a[point] == b'O').map(gps_coordinate).sum::<isize>();
+    let a = grid_a.find_all(b'O').map(gps_coordinate).sum::<isize>();
 
     part2_run(&moves, &mut grid_b);
-    let b = grid_b.area().all_points().filter(|&point| grid_b[point] == b'[').map(gps_coordinate).sum::<isize>();
+    let b = grid_b.find_all(b'[').map(gps_coordinate).sum::<isize>();
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
 
 fn part1_run(moves: &Vec<u8>, grid: &mut Grid<u8>) {
-    let mut robot = Vec2::from_tuple(
-        grid.area()
-            .all_points()
-            .find(|&point| grid[point] == b'@')
-            .unwrap(),
-    );
+    let mut robot = grid.find(b'@').unwrap();
 
     for m in moves {
         let step = step(*m);
@@ -161,12 +156,7 @@ fn try_move_box(grid: &mut Grid<u8>, box_pos: Vec2<isize>, m: u8) {
 }
 
 fn part2_run(moves: &Vec<u8>, grid: &mut Grid<u8>) {
-    let mut robot = Vec2::from_tuple(
-        grid.area()
-            .all_points()
-            .find(|&point| grid[point] == b'@')
-            .unwrap(),
-    );
+    let mut robot = grid.find(b'@').unwrap();
 
     for m in moves {
         let target = robot + step(*m);
@@ -219,18 +209,6 @@ fn widen_grid(grid: &Grid<u8>) -> Grid<u8> {
     wide_grid
 }
 
-#[allow(dead_code)]
-fn print_grid(grid: &Grid<u8>) {
-    for line in grid
-        .data_slice()
-        .chunks_exact(grid.width() as usize)
-        .map(|chunk| std::str::from_utf8(chunk).unwrap())
-    {
-        println!("{}", line);
-    }
-    println!();
-}
-
 fn step(c: u8) -> Vec2<isize> {
     Vec2::from_tuple(match c {
         b'^' => (0, -1),
@@ -241,6 +219,6 @@ fn step(c: u8) -> Vec2<isize> {
     })
 }
 
-fn gps_coordinate((x, y): (isize, isize)) -> isize {
-    x + y * 100
+fn gps_coordinate(pos: Vec2<isize>) -> isize {
+    pos.x + pos.y * 100
 }
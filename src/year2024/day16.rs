@@ -6,7 +6,7 @@
 //
 
 use crate::year2024::day16::Direction::{East, North, South, West};
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, SolutionResult, SolveError, Vec2};
 use itertools::Itertools;
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap, HashSet};
@@ -15,10 +15,10 @@ type State = (Vec2<isize>, Direction);
 type Cost = i64;
 type Move = (State, Cost);
 
-pub fn day16(input: &str) -> SolutionResult {
-    let mut grid = Grid::from_u8(input.as_bytes());
-    let start = (grid_find(&grid, b'S').unwrap(), East);
-    let end_pos = grid_find(&grid, b'E').unwrap();
+pub fn day16(input: &str) -> Result<SolutionResult, SolveError> {
+    let mut grid = Grid::parse(input.as_bytes())?;
+    let start = (grid.find(b'S').unwrap(), East);
+    let end_pos = grid.find(b'E').unwrap();
 
     // Because there can be multiple paths with the same cost that end facing different directions,
     // there's more than one valid end "state" even if there's only one end position
@@ -27,9 +27,10 @@ pub fn day16(input: &str) -> SolutionResult {
     grid[end_pos] = b'.';
 
     let dijkstra_search_results = dijkstra(&grid, start, &ends);
-    let a = ends
+    // The search stops once the cheapest end is found, so some ends may never be reached
+    let a = *ends
         .iter()
-        .map(|e| dijkstra_search_results.dist[e])
+        .filter_map(|e| dijkstra_search_results.dist.get(e))
         .min()
         .unwrap();
 
@@ -37,7 +38,7 @@ pub fn day16(input: &str) -> SolutionResult {
     // all of them in a Vec.
     let best_end_states = ends
         .iter()
-        .filter(|e| dijkstra_search_results.dist[e] == a)
+        .filter(|e| dijkstra_search_results.dist.get(e) == Some(&a))
         .collect_vec();
 
     // Walk all of the best paths we've found and mark them on the map.
@@ -55,9 +56,9 @@ pub fn day16(input: &str) -> SolutionResult {
         }
         grid[state.0] = b'O';
     }
-    let b = grid.data_slice().iter().filter(|&&b| b == b'O').count();
+    let b = grid.count(b'O');
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -135,10 +136,10 @@ impl DijkstraResult {
 fn dijkstra(grid: &Grid<u8>, source: State, targets: &[State]) -> DijkstraResult {
     let mut o = DijkstraResult::new(source);
     let mut queue = BinaryHeap::new();
-    queue.push(Reverse((source, 0)));
+    queue.push(Reverse((0, source)));
 
     let mut target_cost: Option<Cost> = None;
-    while let Some(Reverse((u_state, cost))) = queue.pop() {
+    while let Some(Reverse((cost, u_state))) = queue.pop() {
         if targets.contains(&u_state) {
             target_cost = Some(cost)
         }
@@ -152,7 +153,7 @@ fn dijkstra(grid: &Grid<u8>, source: State, targets: &[State]) -> DijkstraResult
                     set
                 });
                 o.dist.insert(v_neighbor, alt);
-                queue.push(Reverse((v_neighbor, alt)));
+                queue.push(Reverse((alt, v_neighbor)));
             } else if o.dist.contains_key(&v_neighbor) && alt == o.dist[&v_neighbor] {
                 o.prev.get_mut(&v_neighbor).unwrap().insert(u_state);
                 o.dist.insert(v_neighbor, alt);
@@ -167,22 +168,3 @@ fn dijkstra(grid: &Grid<u8>, source: State, targets: &[State]) -> DijkstraResult
 
     o
 }
-
-fn grid_find(grid: &Grid<u8>, target: u8) -> Option<Vec2<isize>> {
-    grid.area()
-        .all_points()
-        .find(|&point| grid[point] == target)
-        .map(Vec2::from_tuple)
-}
-
-#[allow(dead_code)]
-fn print_grid(grid: &Grid<u8>) {
-    for line in grid
-        .data_slice()
-        .chunks_exact(grid.width() as usize)
-        .map(|chunk| std::str::from_utf8(chunk).unwrap())
-    {
-        println!("{}", line);
-    }
-    println!();
-}
@@ -5,13 +5,13 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::SolutionResult;
 use itertools::Itertools;
 
 pub fn day17(input: &str) -> SolutionResult {
     let mut computer = parse_input(input);
     computer.run();
-    let a = computer.output.iter().map(i64::to_string).join(",");
+    let a = computer.output.clone();
 
     // XXX At the moment I don't have a general solution, only a solution for my own input.  A
     // general solution would need to extract and run the beginning of the input program,
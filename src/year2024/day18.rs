@@ -4,12 +4,12 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, Params, SolutionResult, SolveError, Vec2};
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::VecDeque;
 
-pub fn day18(input: &str) -> SolutionResult {
+pub fn day18(input: &str, params: &Params) -> Result<SolutionResult, SolveError> {
     let coordinates = input
         .split(|c: char| !c.is_ascii_digit())
         .filter(|s| !s.is_empty())
@@ -17,15 +17,9 @@ pub fn day18(input: &str) -> SolutionResult {
         .tuples::<(_, _)>()
         .collect_vec();
 
-    let example = coordinates.len() < 1000;
-    let grid_size: Vec2<isize> = match example {
-        true => Vec2::new(7, 7),
-        false => Vec2::new(71, 71),
-    };
-    let iter_count = match example {
-        true => 12,
-        false => 1024,
-    };
+    let size = params.get("size", 71)?;
+    let grid_size: Vec2<isize> = Vec2::new(size, size);
+    let iter_count = params.get("bytes", 1024)?;
 
     let grid = prepare_grid(&coordinates, grid_size, iter_count);
 
@@ -40,7 +34,7 @@ pub fn day18(input: &str) -> SolutionResult {
         .unwrap()
         .1;
 
-    SolutionResult::new(a, format!("{},{}", b.0, b.1))
+    Ok(SolutionResult::new(a, b))
 }
 
 fn part2_test(i: usize, coordinates: &[(isize, isize)]) -> bool {
@@ -67,16 +61,11 @@ fn prepare_grid(
     grid
 }
 
-const CARDINALS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
-
 fn neighbors<'a>(
     grid: &'a Grid<u8>,
     pos: &'a Vec2<isize>,
 ) -> impl Iterator<Item = Vec2<isize>> + 'a {
-    CARDINALS
-        .into_iter()
-        .map(|c| *pos + c)
-        .filter(|c| grid.area().contains(*c) && grid[*c] == b'.')
+    grid.neighbours(*pos).filter(|c| grid[*c] == b'.')
 }
 
 fn bfs(grid: &Grid<u8>) -> Option<Grid<Vec2<isize>>> {
@@ -124,15 +113,3 @@ fn find_path(grid: &Grid<u8>) -> Option<Vec<Vec2<isize>>> {
         }
     }
 }
-
-#[allow(dead_code)]
-fn print_grid(grid: &Grid<u8>) {
-    for line in grid
-        .data_slice()
-        .chunks_exact(grid.width() as usize)
-        .map(|chunk| std::str::from_utf8(chunk).unwrap())
-    {
-        println!("{}", line);
-    }
-    println!();
-}
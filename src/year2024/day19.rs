@@ -4,7 +4,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::SolutionResult;
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashMap;
@@ -55,8 +55,8 @@ fn test_pattern_cached<'a>(
     for towel in matching_towels {
         if *pattern == *towel {
             count += 1;
-        } else if pattern.starts_with(towel) {
-            count += test_pattern_cached(&pattern[towel.len()..], towels, memos);
+        } else if let Some(rest) = pattern.strip_prefix(towel) {
+            count += test_pattern_cached(rest, towels, memos);
         }
     }
 
@@ -4,14 +4,14 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{Grid, SolutionResult, Vec2};
+use crate::aoc::{Grid, SolutionResult, Vec2};
 use itertools::Itertools;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::iter;
 use std::slice::SplitInclusive;
 
 const NUM_KEYPAD_BYTES: &[u8] = b"789\n456\n123\n#0A\n";
-const DIR_KEYPAD_BYTES: &[u8] = b"#^A\n<v>>\n";
+const DIR_KEYPAD_BYTES: &[u8] = b"#^A\n<v>\n";
 
 pub fn day21(input: &str) -> SolutionResult {
     let door_codes = input.lines().map(|l| l.as_bytes()).collect_vec();
@@ -53,8 +53,6 @@ pub fn day21(input: &str) -> SolutionResult {
     SolutionResult::new(a, b)
 }
 
-const CARDINALS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
-
 fn cardinal_to_u8(cardinal: Vec2<isize>) -> u8 {
     match cardinal {
         Vec2 { x: 0, y: 1 } => b'v',
@@ -69,10 +67,7 @@ fn neighbors<'a>(
     grid: &'a Grid<u8>,
     pos: &'a Vec2<isize>,
 ) -> impl Iterator<Item = Vec2<isize>> + 'a {
-    CARDINALS
-        .into_iter()
-        .map(|c| *pos + c)
-        .filter(|c| grid.area().contains(*c) && grid[*c] != b'#')
+    grid.neighbours(*pos).filter(|c| grid[*c] != b'#')
 }
 
 fn bfs(grid: &Grid<u8>, start: Vec2<isize>) -> Grid<Vec<Vec2<isize>>> {
@@ -119,18 +114,11 @@ fn find_paths(
 
 fn path_to_u8(path: &[Vec2<isize>]) -> Vec<u8> {
     path.windows(2)
-        .map(|w| cardinal_to_u8((w[1] - w[0])))
+        .map(|w| cardinal_to_u8(w[1] - w[0]))
         .chain(iter::once(b'A'))
         .collect_vec()
 }
 
-fn grid_find(grid: &Grid<u8>, target: u8) -> Option<Vec2<isize>> {
-    grid.area()
-        .all_points()
-        .find(|&point| grid[point] == target)
-        .map(Vec2::from_tuple)
-}
-
 fn find_keypad_paths(grid: &Grid<u8>) -> BTreeMap<(u8, u8), Vec<Vec<u8>>> {
     let keys = grid
         .data_slice()
@@ -139,10 +127,10 @@ fn find_keypad_paths(grid: &Grid<u8>) -> BTreeMap<(u8, u8), Vec<Vec<u8>>> {
         .collect_vec();
     let mut paths = BTreeMap::<(u8, u8), Vec<Vec<u8>>>::new();
     for button1 in &keys {
-        let pos1 = grid_find(grid, **button1).unwrap();
+        let pos1 = grid.find(**button1).unwrap();
         let predecessors = bfs(grid, pos1);
         for button2 in &keys {
-            let pos2 = grid_find(grid, **button2).unwrap();
+            let pos2 = grid.find(**button2).unwrap();
             let entry = paths.entry((grid[pos1], grid[pos2])).or_default();
             for path in find_paths(&predecessors, pos1, pos2) {
                 entry.push(path_to_u8(&path));
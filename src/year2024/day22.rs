@@ -4,76 +4,86 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::{Answer, Phased};
 use itertools::Itertools;
 use rayon::prelude::*;
 
-pub fn day22(input: &str) -> SolutionResult {
-    let initial_numbers = input
-        .lines()
-        .map(|l| l.parse::<i64>().unwrap())
-        .collect_vec();
+pub struct Day22;
 
-    let computed_secrets = initial_numbers
-        .iter()
-        .map(|n| {
-            itertools::iterate(*n, |n| next_secret(*n))
-                .take(2001)
-                .collect_vec()
-        })
-        .collect_vec();
-    let a = computed_secrets
-        .iter()
-        .map(|sequence| sequence[2000])
-        .sum::<i64>();
+impl Phased for Day22 {
+    /// Each buyer's initial secret followed by the 2000 secrets generated from it
+    type Parsed = Vec<Vec<i64>>;
 
-    let prices = computed_secrets
-        .iter()
-        .map(|sequence| sequence.iter().map(|n| n % 10).collect_vec())
-        .collect_vec();
+    fn parse(input: &str) -> Vec<Vec<i64>> {
+        let initial_numbers = input
+            .lines()
+            .map(|l| l.parse::<i64>().unwrap())
+            .collect_vec();
 
-    let price_changes = prices
-        .iter()
-        .map(|sequence| {
-            sequence
-                .iter()
-                .tuple_windows::<(_, _)>()
-                .map(|(a, b)| b - a)
-                .collect_vec()
-        })
-        .collect_vec();
+        initial_numbers
+            .iter()
+            .map(|n| {
+                itertools::iterate(*n, |n| next_secret(*n))
+                    .take(2001)
+                    .collect_vec()
+            })
+            .collect_vec()
+    }
 
-    let change_sequence_candidates = std::iter::repeat_n((-9..=9), 4)
-        .multi_cartesian_product()
-        .filter(|sequence| sequence.iter().sum::<i64>() >= -9 && sequence.iter().sum::<i64>() <= 9)
-        .collect_vec();
-    let change_windows = price_changes
-        .iter()
-        .map(|sequence| sequence.windows(4).collect_vec())
-        .collect_vec();
-    let change_windows_with_prices = std::iter::zip(change_windows, &prices)
-        .map(|(change_sequence, prices)| {
-            std::iter::zip(change_sequence, &prices[4..]).collect_vec()
-        })
-        .collect_vec();
+    fn part1(computed_secrets: &Vec<Vec<i64>>) -> impl Into<Answer> {
+        computed_secrets
+            .iter()
+            .map(|sequence| sequence[2000])
+            .sum::<i64>()
+    }
 
-    let b = change_sequence_candidates
-        .par_iter()
-        .map(|candidate| {
-            change_windows_with_prices
-                .iter()
-                .map(
-                    |sequence| match sequence.iter().find(|(s, _)| *s == candidate.as_slice()) {
-                        None => 0i64,
-                        Some((_, p)) => **p,
-                    },
-                )
-                .sum::<i64>()
-        })
-        .max()
-        .unwrap();
+    fn part2(computed_secrets: &Vec<Vec<i64>>) -> impl Into<Answer> {
+        let prices = computed_secrets
+            .iter()
+            .map(|sequence| sequence.iter().map(|n| n % 10).collect_vec())
+            .collect_vec();
 
-    SolutionResult::new(a, b)
+        let price_changes = prices
+            .iter()
+            .map(|sequence| {
+                sequence
+                    .iter()
+                    .tuple_windows::<(_, _)>()
+                    .map(|(a, b)| b - a)
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let change_sequence_candidates = std::iter::repeat_n(-9..=9, 4)
+            .multi_cartesian_product()
+            .filter(|sequence| sequence.iter().sum::<i64>() >= -9 && sequence.iter().sum::<i64>() <= 9)
+            .collect_vec();
+        let change_windows = price_changes
+            .iter()
+            .map(|sequence| sequence.windows(4).collect_vec())
+            .collect_vec();
+        let change_windows_with_prices = std::iter::zip(change_windows, &prices)
+            .map(|(change_sequence, prices)| {
+                std::iter::zip(change_sequence, &prices[4..]).collect_vec()
+            })
+            .collect_vec();
+
+        change_sequence_candidates
+            .par_iter()
+            .map(|candidate| {
+                change_windows_with_prices
+                    .iter()
+                    .map(
+                        |sequence| match sequence.iter().find(|(s, _)| *s == candidate.as_slice()) {
+                            None => 0i64,
+                            Some((_, p)) => **p,
+                        },
+                    )
+                    .sum::<i64>()
+            })
+            .max()
+            .unwrap()
+    }
 }
 
 fn next_secret(mut secret: i64) -> i64 {
@@ -4,15 +4,16 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::{SolutionResult, SolveError};
 use itertools::Itertools;
 use std::collections::{BTreeMap, BTreeSet};
 
-pub fn day23(input: &str) -> SolutionResult {
+pub fn day23(input: &str) -> Result<SolutionResult, SolveError> {
     let pairs = input
         .lines()
-        .map(|line| (&line[0..2], &line[3..5]))
-        .collect_vec();
+        .enumerate()
+        .map(|(i, line)| parse_connection(i + 1, line))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mut edges = BTreeMap::new();
     let mut nodes = vec![];
@@ -46,35 +47,101 @@ pub fn day23(input: &str) -> SolutionResult {
         edges_including_self.get_mut(node).unwrap().insert(node);
     }
 
-    let b = solve_part2(&nodes, edges_including_self);
+    let b = solve_part2(&nodes, edges_including_self)?;
 
-    SolutionResult::new(a, b)
+    Ok(SolutionResult::new(a, b))
 }
 
-fn solve_part2(nodes: &Vec<&str>, edges_including_self: BTreeMap<&str, BTreeSet<&str>>) -> String {
-    let max_network_size = edges_including_self.first_key_value().unwrap().1.len();
-    for _ in (0..max_network_size).rev() {
+/// A line like "kh-tc", with the error pointing at the part that doesn't fit
+fn parse_connection(line_number: usize, line: &str) -> Result<(&str, &str), SolveError> {
+    let error = |token: &str, message| SolveError::parse_at(line_number, line, token, message);
+    match line.split_once('-') {
+        Some((a, _)) if a.len() != 2 => Err(error(a, "expected a two-letter computer")),
+        Some((_, b)) if b.len() != 2 => Err(error(b, "expected a two-letter computer")),
+        Some((a, b)) => Ok((a, b)),
+        None => {
+            // Point at where the '-' should have been
+            let dash = line
+                .char_indices()
+                .nth(2)
+                .map_or(line.len(), |(offset, _)| offset);
+            Err(error(
+                &line[dash..],
+                "expected '-' after the first computer",
+            ))
+        }
+    }
+}
+
+fn solve_part2(
+    nodes: &Vec<&str>,
+    edges_including_self: BTreeMap<&str, BTreeSet<&str>>,
+) -> Result<Vec<String>, SolveError> {
+    // No party can be bigger than the best connected computer and the computers it's connected to
+    let max_party_size = edges_including_self
+        .values()
+        .map(BTreeSet::len)
+        .max()
+        .ok_or_else(|| SolveError::Unsolvable("no connections".to_string()))?;
+    for party_size in (1..=max_party_size).rev() {
         for node1 in nodes {
-            for candidate_party in edges_including_self
-                .get(node1)
-                .unwrap()
-                .iter()
-                .combinations(13)
-            {
+            for candidate_party in edges_including_self[node1].iter().combinations(party_size) {
                 let matched = candidate_party.iter().all(|node2| {
                     candidate_party
                         .iter()
                         .all(|b| edges_including_self[*node2].contains(*b))
                 });
                 if matched {
-                    return candidate_party.iter().join(",");
+                    return Ok(candidate_party
+                        .iter()
+                        .map(|node| node.to_string())
+                        .collect());
                 }
             }
         }
     }
-    panic!("No solution found");
+    Err(SolveError::Unsolvable(
+        "no fully connected party".to_string(),
+    ))
 }
 
 fn has_computer_starting_with_t(triple: &[&str; 3]) -> bool {
     triple.iter().any(|s| s.starts_with('t'))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_connections() {
+        assert_eq!(
+            day23("kh-tc\nqp-khx\n"),
+            Err(SolveError::Parse {
+                line: 2,
+                column: 4,
+                message: "expected a two-letter computer: \"khx\"".to_string(),
+            })
+        );
+        assert_eq!(
+            day23("kh-tc\nqp-kh\nde_cg\n"),
+            Err(SolveError::Parse {
+                line: 3,
+                column: 3,
+                message: "expected '-' after the first computer: \"_cg\"".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn no_connections() {
+        assert_eq!(
+            day23(""),
+            Err(SolveError::Unsolvable("no connections".to_string()))
+        );
+        assert!(matches!(
+            day23("\n\n"),
+            Err(SolveError::Parse { line: 1, .. })
+        ));
+    }
+}
@@ -4,11 +4,11 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::SolutionResult;
+use crate::aoc::{Answer, SolutionResult};
 use itertools::Itertools;
 use regex::Regex;
 use std::cmp::PartialEq;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 use std::str::FromStr;
 use strum_macros::EnumString;
 
@@ -46,17 +46,18 @@ pub fn day24(input: &str) -> SolutionResult {
         .collect::<String>();
     let a = i64::from_str_radix(&str, 2).unwrap();
 
-    // Print GraphViz DOT input which can be fed into GraphViz to visualize the graph of adders
-    println!("digraph G {{");
-    println!("  layout = dot;");
+    // Print GraphViz DOT input which can be fed into GraphViz to visualize the graph of adders.
+    // Like the diagnostics below it goes to stderr, keeping stdout for the answers or report.
+    eprintln!("digraph G {{");
+    eprintln!("  layout = dot;");
     for (out, gate) in &gates {
-        println!("  {}_{}_{} -> {out};", gate.in1, gate.in2, gate.op);
-        println!("  {} -> {}_{}_{};", gate.in1, gate.in1, gate.in2, gate.op);
-        println!("  {} -> {}_{}_{};", gate.in2, gate.in1, gate.in2, gate.op);
+        eprintln!("  {}_{}_{} -> {out};", gate.in1, gate.in2, gate.op);
+        eprintln!("  {} -> {}_{}_{};", gate.in1, gate.in1, gate.in2, gate.op);
+        eprintln!("  {} -> {}_{}_{};", gate.in2, gate.in1, gate.in2, gate.op);
     }
-    println!("}}");
+    eprintln!("}}");
 
-    for (out, gate) in &gates {
+    for out in gates.keys() {
         if !out.starts_with('z') || *out == "z00" || out == gates.last_key_value().unwrap().0 {
             continue;
         }
@@ -74,7 +75,10 @@ pub fn day24(input: &str) -> SolutionResult {
     // swapping with every other output to find the swap that resolves the problem.  There are few
     // enough "problem" outputs that this should run in a reasonable amount of time.
 
-    SolutionResult::new(a, "")
+    SolutionResult {
+        a: Answer::from(a),
+        b: Answer::NotImplemented,
+    }
 }
 
 fn evaluate_gate(out: &str, gates: &BTreeMap<&str, Gate>, inputs: &BTreeMap<&str, bool>) -> bool {
@@ -99,15 +103,15 @@ fn verify_adder(out: &str, gates: &BTreeMap<&str, Gate>, inputs: &BTreeMap<&str,
     let gate = gates.get(out).unwrap();
 
     if gate.op != Op::Xor {
-        println!("{out}: has wrong input gate: {}", gate.op);
+        eprintln!("{out}: has wrong input gate: {}", gate.op);
         return false;
     }
     if inputs.contains_key(gate.in1) {
-        println!("{out}: Input {} should not be connected to {}'s XOR gate", gate.in1, out);
+        eprintln!("{out}: Input {} should not be connected to {}'s XOR gate", gate.in1, out);
         return false;
     }
     if inputs.contains_key(gate.in2) {
-        println!("{out}: Input {} should not be connected to {}'s XOR gate", gate.in2, out);
+        eprintln!("{out}: Input {} should not be connected to {}'s XOR gate", gate.in2, out);
         return false;
     }
     // One of the XOR inputs is a carry bit (OR) from the previous adder
@@ -120,26 +124,26 @@ fn verify_adder(out: &str, gates: &BTreeMap<&str, Gate>, inputs: &BTreeMap<&str,
     }
     if gates.get(carry).unwrap().op != Op::Or {
         // FIXME: z01 carry bit is an AND, not an OR
-        println!("{}: Could not identify a carry bit for output", out);
+        eprintln!("{}: Could not identify a carry bit for output", out);
         return false;
     }
     if gates.get(in_xor).unwrap().op != Op::Xor {
-        println!("{}: Could not identify a adder input XOR for output", out);
+        eprintln!("{}: Could not identify a adder input XOR for output", out);
         return false;
     }
     
     let in_xor_gate = gates.get(in_xor).unwrap();
     if in_xor_gate.in1[1..] != out[1..] {
-        println!("{}: has incorrect input bit {}", out, in_xor_gate.in1);
+        eprintln!("{}: has incorrect input bit {}", out, in_xor_gate.in1);
     }
     if in_xor_gate.in2[1..] != out[1..] {
-        println!("{}: has incorrect input bit {}", out, in_xor_gate.in2);
+        eprintln!("{}: has incorrect input bit {}", out, in_xor_gate.in2);
     }
     
     true
 }
 
-fn parse_input(input: &str) -> (BTreeMap<&str, bool>, BTreeMap<&str, Gate>) {
+fn parse_input(input: &str) -> (BTreeMap<&str, bool>, BTreeMap<&str, Gate<'_>>) {
     let lines = input.lines().collect_vec();
     let split = lines.iter().position(|l| l.is_empty()).unwrap();
 
@@ -4,13 +4,20 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::{Grid, SolutionResult};
+use crate::aoc::{Answer, Grid, GridError, SolutionResult, SolveError};
 use arrayvec::ArrayVec;
 use itertools::Itertools;
 
-pub fn day25(input: &str) -> SolutionResult {
-    assert!(!input.as_bytes().contains(&b'\r'));
-    let grids = input.as_bytes().chunks(43).map(Grid::from_u8).collect_vec();
+pub fn day25(input: &str) -> Result<SolutionResult, SolveError> {
+    // Each schematic is 7 lines and a blank one; errors count lines from the start of the input
+    let grids = input
+        .as_bytes()
+        .chunks(43)
+        .enumerate()
+        .map(|(i, schematic)| {
+            Grid::parse(schematic).map_err(|err| GridError { line: i * 8 + err.line, ..err })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mut locks = vec![];
     let mut keys = vec![];
@@ -37,5 +44,27 @@ pub fn day25(input: &str) -> SolutionResult {
         .filter(|heights| heights.iter().all(|h| *h <= 7))
         .count();
 
-    SolutionResult::new(a, "🎄")
+    Ok(SolutionResult {
+        a: Answer::from(a),
+        b: Answer::NoPart2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ragged_schematic() {
+        let input = "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....\n\n\
+                     .....\n#....\n#.##\n#...#\n#.#.#\n#.###\n#####\n";
+        assert_eq!(
+            day25(input),
+            Err(SolveError::Parse {
+                line: 11,
+                column: 5,
+                message: "line 11 is 4 wide, but the grid is 5 wide".to_string(),
+            })
+        );
+    }
 }
@@ -30,3 +30,32 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+
+crate::register_solutions! {
+    2024;
+    1 => day01: Fallible,
+    2 => day02,
+    3 => day03,
+    4 => day04: Bytes,
+    5 => day05,
+    6 => day06: Fallible,
+    7 => day07,
+    8 => day08: Fallible,
+    9 => day09,
+    10 => day10: Fallible,
+    11 => day11,
+    12 => day12: Fallible,
+    13 => day13,
+    14 => day14: Params,
+    15 => day15: Fallible,
+    16 => day16: Fallible,
+    17 => day17,
+    18 => day18: Params,
+    19 => day19,
+    20 => day20: Params,
+    21 => day21,
+    22 => day22: Phased<Day22>,
+    23 => day23: Fallible,
+    24 => day24,
+    25 => day25: Fallible,
+}
@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 
 // To avoid having to deal with wrapping when underflowing, just use a big
 // number and only look at the last two digits to know the actual dial value.
@@ -62,8 +62,5 @@ pub fn day01(input: &str) -> SolutionResult {
         }
     }
 
-    SolutionResult {
-        a: p1_zero_count.to_string(),
-        b: p2_zero_count.to_string(),
-    }
+    SolutionResult::new(p1_zero_count, p2_zero_count)
 }
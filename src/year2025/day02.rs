@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 
 pub fn day02(input: &str) -> SolutionResult {
@@ -17,7 +17,7 @@ pub fn day02(input: &str) -> SolutionResult {
     let chunks = number_strs.iter().chunks(2);
     let all_numbers_in_ranges = chunks.into_iter().flat_map(|chunk| {
         let pair = chunk.collect_tuple::<(_, _)>().unwrap();
-        pair.0.parse::<i64>().unwrap()..pair.1.parse::<i64>().unwrap()
+        pair.0.parse::<i64>().unwrap()..=pair.1.parse::<i64>().unwrap()
     }).collect::<Vec<i64>>();
 
     let a = all_numbers_in_ranges
@@ -30,10 +30,7 @@ pub fn day02(input: &str) -> SolutionResult {
         .filter(|id| is_invalid_num_b(**id))
         .sum::<i64>();
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult::new(a, b)
 }
 
 fn is_invalid_str_a(id: &str) -> bool {
@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 
 pub fn day03(input: &str) -> SolutionResult {
@@ -14,10 +14,7 @@ pub fn day03(input: &str) -> SolutionResult {
     let a = sum_joltage(lines.clone().map(|line| line.as_bytes()), 2);
     let b = sum_joltage(lines.clone().map(|line| line.as_bytes()), 12);
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult::new(a, b)
 }
 
 type Joltage = u64;
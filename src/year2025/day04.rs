@@ -5,10 +5,10 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 
-pub fn day04(input: &str) -> SolutionResult {
-    let mut grid = Grid::from_u8(input.as_bytes());
+pub fn day04(input: &str) -> Result<SolutionResult, SolveError> {
+    let mut grid = Grid::parse(input.as_bytes())?;
 
     let a = grid
         .area()
@@ -16,39 +16,17 @@ pub fn day04(input: &str) -> SolutionResult {
         .filter(|p| check_position(&grid, Vec2::from_tuple(*p)))
         .count();
 
-    let rolls_before = grid.data_slice().iter().filter(|&&t| t == b'@').count();
+    let rolls_before = grid.count(b'@');
     remove_all_possible_rolls(&mut grid);
-    let rolls_after = grid.data_slice().iter().filter(|&&t| t == b'@').count();
+    let rolls_after = grid.count(b'@');
 
     let b = rolls_before - rolls_after;
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
-}
-
-const NEIGHBOR_DIRECTIONS: [(isize, isize); 8] = [
-    (0, 1),
-    (1, 0),
-    (0, -1),
-    (-1, 0),
-    (1, -1),
-    (1, 1),
-    (-1, 1),
-    (-1, -1),
-];
-
-fn neighbor_tiles<'a>(grid: &'a Grid<u8>, pos: Vec2<isize>) -> impl Iterator<Item = u8> + 'a {
-    NEIGHBOR_DIRECTIONS
-        .into_iter()
-        .map(move |d| pos + d)
-        .filter(|&p| grid.area().contains(p))
-        .map(|p| grid[p])
+    Ok(SolutionResult::new(a, b))
 }
 
 fn count_neighbor_rolls(grid: &Grid<u8>, pos: Vec2<isize>) -> usize {
-    neighbor_tiles(grid, pos).filter(|&t| t == b'@').count()
+    grid.neighbour_values8(pos).filter(|&&t| t == b'@').count()
 }
 
 fn check_position(grid: &Grid<u8>, p: Vec2<isize>) -> bool {
@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 use std::collections::BTreeMap;
 
@@ -19,10 +19,7 @@ pub fn day05(input: &str) -> SolutionResult {
     let a = ingredients.iter().filter(is_fresh_predicate).count();
     let b = count_fresh(&fresh_intervals);
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult::new(a, b)
 }
 
 type IngredientId = u64;
@@ -59,7 +56,7 @@ fn process_input(input: &str) -> ProcessedInput {
         // Remove any existing entries in this interval
         let keys_to_remove = fresh_intervals
             .range(left_inclusive..=right_inclusive)
-            .map(|(k, v)| *k)
+            .map(|(k, _)| *k)
             .collect_vec();
         for key in keys_to_remove {
             fresh_intervals.remove(&key);
@@ -5,96 +5,56 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 
 pub fn day06(input: &str) -> SolutionResult {
-    let lines = input.lines().collect_vec();
-    let input_width = lines.iter().map(|l| l.len()).max().unwrap();
-    let input_height = lines.len();
+    // The problems' columns are ragged, so pad them out to make a grid
+    let grid = Grid::parse_padded(input.as_bytes(), b' ');
+    let ops_row = grid.height() - 1;
 
-    let op_chars_by_index = lines
-        .last()
-        .unwrap()
-        .chars()
-        .enumerate()
-        .filter(|(_, c)| *c != ' ')
+    // Each problem's operator is in its leftmost column, and a blank column follows it
+    let op_columns = (0..grid.width())
+        .filter(|&x| grid[(x, ops_row)] != b' ')
         .collect_vec();
-
-    let ops = op_chars_by_index.iter().map(|(_, c)| *c).collect_vec();
-
-    let problem_column_fields = op_chars_by_index
+    let problem_columns = op_columns
         .iter()
-        .map(|(i, _)| *i)
-        .chain([input_width + 1])
+        .copied()
+        .chain([grid.width() + 1])
         .tuple_windows::<(_, _)>()
-        .map(|(l, r)| (l, r - l - 1))
+        .map(|(l, r)| l..r - 1)
         .collect_vec();
 
-    let problem_count = problem_column_fields.len();
-
-    let mut grid = Grid::<char>::new((problem_count * 4) as isize, (input_height - 1) as isize);
-    for (x, y) in grid.area().all_points() {
-        grid[(x, y)] = ' ';
-    }
-
-    for (y, line) in lines.iter().dropping_back(1).enumerate() {
-        for (i, (input_x, width)) in problem_column_fields.iter().enumerate() {
-            let input_x_end = std::cmp::min(*input_x + *width, line.len());
-            let copy_from = &line[*input_x..input_x_end];
-            let copy_to_x = (i * 4) as isize;
-            for (i2, c) in copy_from.chars().enumerate() {
-                grid[(copy_to_x + i2 as isize, y as isize)] = c;
-            }
-        }
-    }
-
     let mut a = 0;
     let mut b = 0;
 
-    for problem_index in 0..problem_count {
-        let x_range = (problem_index * 4) as isize..((problem_index + 1) * 4) as isize;
-        let y_range = 0..(input_height - 1) as isize;
+    for columns in problem_columns {
+        let op = grid[(columns.start, ops_row)];
+        let rows = 0..ops_row;
+
+        let solve_problems = |nums: &Vec<u64>| match op {
+            b'+' => nums.iter().sum::<u64>(),
+            b'*' => nums.iter().product::<u64>(),
+            _ => panic!("Unknown op: {}", op as char),
+        };
 
         // Human numbers
-        let human_nums = y_range
+        let human_nums = rows
             .clone()
-            .map(|y| {
-                let start = ((y * grid.width()) as usize + (problem_index * 4) as usize);
-                grid.data_slice()[start..start + 4]
-                    .into_iter()
-                    .collect::<String>()
-                    .trim()
-                    .parse::<u64>()
-            }).flatten()
+            .flat_map(|y| parse_number(columns.clone().map(|x| grid[(x, y)])))
             .collect_vec();
-
-        let solve_problems = |nums: &Vec<u64>| match ops[problem_index] {
-            '+' => nums.iter().sum::<u64>(),
-            '*' => nums.iter().product::<u64>(),
-            _ => panic!("Unknown op: {}", ops[problem_index]),
-        };
         a += solve_problems(&human_nums);
 
         // Cephalopod numbers
-        let cephalopod_nums = x_range
-            .clone()
-            .map(|place| {
-                y_range
-                    .clone()
-                    .map(|y| grid[Vec2::from_tuple((place as isize, y))])
-                    .collect::<String>()
-                    .trim()
-                    .parse::<u64>()
-            })
-            .flatten()
+        let cephalopod_nums = columns
+            .flat_map(|x| parse_number(rows.clone().map(|y| grid[(x, y)])))
             .collect_vec();
-
         b += solve_problems(&cephalopod_nums);
     }
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult::new(a, b)
+}
+
+fn parse_number(chars: impl Iterator<Item = u8>) -> Option<u64> {
+    chars.map(char::from).collect::<String>().trim().parse().ok()
 }
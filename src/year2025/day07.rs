@@ -5,13 +5,13 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 
 pub fn day07(input: &str) -> SolutionResult {
     let mut input_lines: Vec<Vec<u8>> = input
         .lines()
-        .map(|s| s.as_bytes().iter().copied().collect())
+        .map(|s| s.as_bytes().to_vec())
         .collect();
 
     let mut beam_timeline_counts: Vec<Vec<u64>> = input_lines
@@ -56,10 +56,7 @@ pub fn day07(input: &str) -> SolutionResult {
         .copied()
         .sum::<u64>();
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult::new(a, b)
 }
 
 fn get_mut2<T>(v: &mut [T], i: usize, j: usize) -> Option<(&mut T, &mut T)> {
@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
@@ -38,7 +38,7 @@ impl JunctionBox {
     }
 }
 
-pub fn day08(input: &str) -> SolutionResult {
+pub fn day08(input: &str, params: &Params) -> Result<SolutionResult, SolveError> {
     let boxes: Vec<JunctionBox> = input.lines().map_into().collect();
     let mut pairs = boxes
         .iter()
@@ -54,8 +54,7 @@ pub fn day08(input: &str) -> SolutionResult {
         circuits_by_members.insert(b, Rc::new(RefCell::from(vec![b])));
     }
 
-    let is_example = boxes.len() < 1000;
-    let boxes_to_connect = if is_example { 10 } else { 1000 };
+    let boxes_to_connect = params.get("connections", 1000)?;
 
     let mut connected_count = 0usize;
     let mut last_connection: Option<(JunctionBox, JunctionBox)> = None;
@@ -68,7 +67,7 @@ pub fn day08(input: &str) -> SolutionResult {
         let l_circuit_rc = circuits_by_members.get(l).cloned().unwrap();
         let r_circuit_rc = circuits_by_members.get(r).cloned().unwrap();
         if l_circuit_rc != r_circuit_rc {
-            let mut r_circuit = r_circuit_rc.borrow_mut();
+            let r_circuit = r_circuit_rc.borrow_mut();
 
             {
                 // Merge circuit r into l.
@@ -83,7 +82,7 @@ pub fn day08(input: &str) -> SolutionResult {
                 circuits_by_members.insert(*junction, l_circuit_rc.clone());
             }
         }
-        connected_count = connected_count + 1;
+        connected_count += 1;
 
         if connected_count == boxes_to_connect {
             let mut circuit_rcs: Vec<Circuit> = circuits_by_members
@@ -91,7 +90,7 @@ pub fn day08(input: &str) -> SolutionResult {
                 .cloned()
                 .unique_by(|rc| Rc::as_ptr(rc) as usize)
                 .collect();
-            circuit_rcs.sort_by(|l, r| r.borrow().len().cmp(&l.borrow().len()));
+            circuit_rcs.sort_by_key(|circuit| std::cmp::Reverse(circuit.borrow().len()));
             a = circuit_rcs
                 .iter()
                 .take(3)
@@ -103,8 +102,5 @@ pub fn day08(input: &str) -> SolutionResult {
     let (b1, b2) = last_connection.unwrap();
     let b = b1.x * b2.x;
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    Ok(SolutionResult::new(a, b))
 }
@@ -5,8 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
-use image::{ImageBuffer, RgbImage};
+use crate::aoc::*;
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::VecDeque;
@@ -48,7 +47,7 @@ pub fn day09(input: &str) -> SolutionResult {
     let mut input_x_points = points.iter().cloned().map(|v| v.x).collect_vec();
     input_x_points.sort_unstable();
     input_x_points.dedup();
-    let mut input_y_points = points.iter().cloned().map(|(v)| v.y).collect_vec();
+    let mut input_y_points = points.iter().cloned().map(|v| v.y).collect_vec();
     input_y_points.sort_unstable();
     input_y_points.dedup();
 
@@ -121,14 +120,10 @@ pub fn day09(input: &str) -> SolutionResult {
         }
     }
 
-    compressed_grid
-        .data_mut_slice()
-        .iter_mut()
-        .for_each(|&mut ref mut c| {
-            if *c == ' ' {
-                *c = 'x'
-            }
-        });
+    let mut compressed_grid = compressed_grid.map(|&c| match c {
+        ' ' => 'x',
+        _ => c,
+    });
 
     // Filter the rectangle list we already found by whether or not all the rectangle contents are
     // red and green only.
@@ -186,17 +181,14 @@ pub fn day09(input: &str) -> SolutionResult {
     //         .unwrap_or_else(|_| eprintln!("Failed to save image"));
     // }
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult::new(a, b)
 }
 
 fn rect_area((a, b): &(Vec2<i64>, Vec2<i64>)) -> i64 {
     ((a.x - b.x).abs() + 1) * ((a.y - b.y).abs() + 1)
 }
 
-fn generate_interval_list(input_points: &Vec<i64>) -> Vec<(i64, i64)> {
+fn generate_interval_list(input_points: &[i64]) -> Vec<(i64, i64)> {
     // Intervals are all (inclusive, exclusive) pairs.
     let mut intervals: Vec<(i64, i64)> = vec![(0, input_points[0])];
     for (a, b) in input_points.iter().cloned().tuple_windows::<(_, _)>() {
@@ -5,15 +5,14 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
-use bitvec::prelude::*;
+use crate::aoc::*;
 use itertools::Itertools;
 use regex::Regex;
 
 type LightSet = u16;
 const EMPTY_LIGHTSET: LightSet = 0u16;
 
-fn set_bit(val: &mut u16, idx: usize, set: bool) -> () {
+fn set_bit(val: &mut u16, idx: usize, set: bool) {
     if set {
         *val |= 1 << idx;
     } else {
@@ -25,13 +24,14 @@ fn set_bit(val: &mut u16, idx: usize, set: bool) -> () {
 struct Machine {
     lights: LightSet,
     buttons: Vec<LightSet>,
+    #[allow(dead_code)]
     joltages: Vec<u8>,
 }
 
 impl Machine {
     fn solve(&self) -> u32 {
         let mut best: Option<u32> = None;
-        for mut i in 1..2u32.pow(self.buttons.len() as u32) {
+        for i in 1..2u32.pow(self.buttons.len() as u32) {
             let mut button_idx_bits = i;
             let mut combined_buttons = EMPTY_LIGHTSET;
 
@@ -61,7 +61,7 @@ impl Machine {
 impl From<&str> for Machine {
     fn from(line: &str) -> Self {
         let input_regex = Regex::new(r"\[(.+)] (.+) \{(.+)}").unwrap();
-        let (_, [a, b, c]) = input_regex.captures(line).unwrap().extract();
+        let (_, [a, b, _joltages]) = input_regex.captures(line).unwrap().extract();
 
         let mut lights = EMPTY_LIGHTSET;
         for (i, c) in a.chars().enumerate() {
@@ -100,10 +100,9 @@ pub fn day10(input: &str) -> SolutionResult {
     // }
 
     let a = machines.iter().map(Machine::solve).sum::<u32>();
-    let b = "";
 
     SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
+        a: Answer::from(a),
+        b: Answer::NotImplemented,
     }
 }
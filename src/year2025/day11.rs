@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
@@ -90,7 +90,7 @@ fn count_all_length_n_paths<'problem_input>(
         if let Some(src_children) = children_map.get(src) {
             for child in src_children {
                 if let Some(child_paths_to_dest) = n_minus_one_path_counts.get(&(child, dest)) {
-                    let mut src_paths_to_dest = out.entry((src, dest)).or_insert(0);
+                    let src_paths_to_dest = out.entry((src, dest)).or_insert(0);
                     *src_paths_to_dest += child_paths_to_dest;
                 }
             }
@@ -126,7 +126,7 @@ pub fn day11(input: &str) -> SolutionResult {
         for &child in &children {
             parents_map
                 .entry(child)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(parent);
         }
         children_map.insert(parent, children);
@@ -170,13 +170,10 @@ pub fn day11(input: &str) -> SolutionResult {
         svr_to_dac * dac_to_fft * fft_to_out,
     );
     let b = if b == 0 {
-        "No Solution".to_string()
+        Answer::GaveUp
     } else {
-        b.to_string()
+        Answer::from(b)
     };
 
-    SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
-    }
+    SolutionResult { a: Answer::from(a), b }
 }
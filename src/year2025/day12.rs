@@ -5,7 +5,7 @@
 // file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
 //
 
-use adventofcode_rust::aoc::*;
+use crate::aoc::*;
 use itertools::Itertools;
 use std::fmt::{Display, Formatter};
 use std::ops::{Index, IndexMut};
@@ -24,8 +24,7 @@ impl Shape {
     pub fn new(data: &[&str; 3]) -> Self {
         let mut out: Self = Default::default();
         data.iter()
-            .map(|row| row.as_bytes())
-            .flatten()
+            .flat_map(|row| row.as_bytes())
             .enumerate()
             .for_each(|(i, c)| out.data[i] = *c);
         out
@@ -35,11 +34,13 @@ impl Shape {
         (y * 3 + x) as usize
     }
 
+    #[allow(dead_code)]
     fn index_to_coord(index: usize) -> (isize, isize) {
         let index = index as isize;
         (index % 3, index / 3)
     }
 
+    #[allow(dead_code)]
     const EDGE_COORDS_IN_ORDER: [(isize, isize); 8] = [
         (0, 0),
         (1, 0),
@@ -51,6 +52,7 @@ impl Shape {
         (0, 1),
     ];
 
+    #[allow(dead_code)]
     pub fn rotated(&self) -> Self {
         let mut out: Self = Default::default();
         for i in 0..8 {
@@ -60,11 +62,14 @@ impl Shape {
         }
         out
     }
+}
 
-    fn print(&self) {
-        println!("{}", str::from_utf8(&self.data[0..3]).unwrap());
-        println!("{}", str::from_utf8(&self.data[3..6]).unwrap());
-        println!("{}", str::from_utf8(&self.data[6..9]).unwrap());
+impl Display for Shape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.data.chunks(3) {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
     }
 }
 
@@ -107,7 +112,7 @@ impl Region {
         let min_area_needed = self
             .shape_counts
             .iter()
-            .map(|n| (*n as u32) * 7)
+            .map(|n| *n * 7)
             .sum::<u32>();
         if region_area < min_area_needed {
             return Some(false);
@@ -143,7 +148,7 @@ impl Display for Region {
 pub fn day12(input: &str) -> SolutionResult {
     let lines = input.lines().collect_vec();
     let shape_lines = &lines[0..30];
-    let shapes = shape_lines
+    let _shapes = shape_lines
         .chunks(5)
         .map(|chunk| Shape::new(<&[&str; 3]>::try_from(&chunk[1..4]).unwrap()))
         .collect_vec();
@@ -170,17 +175,16 @@ pub fn day12(input: &str) -> SolutionResult {
 
     let a_solutions = regions.iter().map(Region::solve_part1).collect_vec();
     for (i, a_solution) in a_solutions.iter().enumerate() {
-        println!("{}: {:?}", i, a_solution);
+        eprintln!("{}: {:?}", i, a_solution);
     }
     let a = if a_solutions.iter().any(|&x| x.is_none()) {
-        "Failed to solve problem".to_string()
+        Answer::GaveUp
     } else {
-        regions.iter().map(Region::solve_part1).filter(|&x| x == Some(true)).count().to_string()
+        Answer::from(regions.iter().map(Region::solve_part1).filter(|&x| x == Some(true)).count())
     };
-    let b = "";
 
     SolutionResult {
-        a: a.to_string(),
-        b: b.to_string(),
+        a,
+        b: Answer::NoPart2,
     }
 }
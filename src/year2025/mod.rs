@@ -17,3 +17,19 @@ pub mod day09;
 pub mod day10;
 pub mod day11;
 pub mod day12;
+
+crate::register_solutions! {
+    2025;
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04: Fallible,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08: Params,
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+}
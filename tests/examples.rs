@@ -0,0 +1,43 @@
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! Every example input under `data/`, checked against its `.answers` file.  The tests themselves
+//! are generated by `build.rs`.
+
+use adventofcode_rust::aoc::{Answers, Date, Input, Verdict};
+use std::path::Path;
+
+#[allow(dead_code)] // with every year's feature disabled there are no examples to check
+fn check_example(year: u32, day: u32, name: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data")
+        .join(year.to_string())
+        .join(format!("{name}.txt"));
+    let date = Date::new(year, day);
+    let registry = adventofcode_rust::registry();
+    let solution = registry
+        .get(date)
+        .unwrap_or_else(|| panic!("{date} has an example but no registered solution"));
+    let input = Input::load(&path).unwrap();
+    let answers = Answers::load(&path).unwrap();
+
+    let result = solution
+        .run(&input)
+        .unwrap_or_else(|err| panic!("{name}: {err}"));
+    let [a, b] = answers.verify(&result);
+    assert!(
+        !matches!(a, Verdict::Fail { .. }),
+        "{name} part 1: got {}, {a}",
+        result.a
+    );
+    assert!(
+        !matches!(b, Verdict::Fail { .. }),
+        "{name} part 2: got {}, {b}",
+        result.b
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
@@ -0,0 +1,58 @@
+// Copyright (c) 2025 David Holmes (dholmes at dholmes dot us)
+//
+// Distributed under the Boost Software License, Version 1.0. (See accompanying
+// file LICENSE_1_0.txt or copy at http://www.boost.org/LICENSE_1_0.txt)
+//
+
+//! The machine-readable reports, read back from the runner's stdout the way another program would.
+//! 2024 day 24 prints a diagram while it solves, which has to stay out of the report.
+
+#![cfg(feature = "y2024")]
+
+use std::path::Path;
+use std::process::Command;
+
+fn report(format: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_adventofcode-rust"))
+        .args([
+            "--year",
+            "2024",
+            "--day",
+            "24",
+            "--example",
+            "--format",
+            format,
+        ])
+        .env(
+            adventofcode_rust::aoc::DATA_DIR_ENV_VAR,
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+        )
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn json_report_parses() {
+    let report: serde_json::Value = serde_json::from_str(&report("json")).unwrap();
+    let records = report.as_array().unwrap();
+    assert_eq!(records.len(), 2);
+    for record in records {
+        assert_eq!(record["year"], 2024);
+        assert_eq!(record["day"], 24);
+    }
+}
+
+#[test]
+fn csv_report_is_only_rows() {
+    let report = report("csv");
+    let mut lines = report.lines();
+    let columns = lines.next().unwrap().split(',').count();
+    assert_eq!(lines.clone().count(), 2);
+    assert!(lines.all(|line| line.starts_with("2024,24,") && line.split(',').count() == columns));
+}